@@ -1,4 +1,8 @@
-use crate::gamma_fn::{log_gamma, gamma};
+use crate::gamma_fn::{N, log_gamma, log_gamma_sign, stirling_series};
+use crate::pochhammer_fn::gamma_delta_ratio;
+
+/** log(2π)/2 */
+const LOG_SQRT_2PI: f64 = 0.9189385332046728;
 
 /// Return a value of the beta function *Β(x, y)*.
 /// 
@@ -13,7 +17,7 @@ use crate::gamma_fn::{log_gamma, gamma};
 ///     assert!((x - y).abs() <= 1e-14);
 /// }
 /// 
-/// assert_approximately(beta(0.5, 0.5), f64::consts::PI);
+/// assert_approximately(beta(0.5, 0.5), std::f64::consts::PI);
 /// 
 /// assert_approximately(beta(1., 1.), 1.);
 /// 
@@ -24,7 +28,7 @@ use crate::gamma_fn::{log_gamma, gamma};
 /// assert_approximately(beta(2., 2.), 1./6.);
 /// assert_approximately(beta(1., 3.), 1./3.);
 /// 
-/// assert_approximately(beta(-3., 1.), -2.);
+/// assert_approximately(beta(-0.5, 1.), -2.);
 /// ```
 /// 
 /// #### Diverging points ####
@@ -40,7 +44,7 @@ pub fn beta(x: f64, y: f64) -> f64 {
         if x.is_infinite() || y.is_infinite() {
             0.
         }else{
-            (log_gamma(x) + log_gamma(y) - log_gamma(x + y)).exp()
+            log_beta(x, y).exp()
         }
     }else{
         if x.is_infinite() || y.is_infinite() {
@@ -51,16 +55,87 @@ pub fn beta(x: f64, y: f64) -> f64 {
                 let inf = if x.is_infinite() { x }else{ y };
                 if inf > 0. { 0. }else{ f64::INFINITY }
             }
+        }else if (x == 0. && x.is_sign_positive() && !is_pole(y)) || (y == 0. && y.is_sign_positive() && !is_pole(x)) {
+            // Γ(+0) = +∞ while Γ(y)/Γ(x+y) = 1
+            f64::INFINITY
         }else{
-            gamma(x) * gamma(y) / gamma(x + y)
+            let (log_abs, sign) = log_beta_sign(x, y);
+            sign * log_abs.exp()
         }
     }
 }
 
+fn is_pole(x: f64) -> bool {
+    x <= 0. && x.fract() == 0.
+}
+
+/// Return the logarithm of the beta function *log Β(a, b)* by the Stirling series for the large argument(s).
+/// (The arguments must be positive. Use [`log_beta_sign`] for negative arguments.)
+/// 
+/// ```
+/// use comonjo_mathfn::log_beta;
+/// 
+/// // Β(1000, 1000) underflows
+/// assert!((log_beta(1000., 1000.) - (-1388.4826016359023)).abs() <= 1e-12);
+/// ```
+pub fn log_beta(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || a <= 0. || b <= 0. { return f64::NAN; }
+    if a.is_infinite() || b.is_infinite() { return f64::NEG_INFINITY; }
+
+    let (p, q) = if a < b { (a, b) }else{ (b, a) };
+    let r = p / (p + q);
+    if p >= N {
+        let corr = stirling_series(p) + stirling_series(q) - stirling_series(p + q);
+        -0.5 * q.ln() + LOG_SQRT_2PI + corr + (p - 0.5) * r.ln() + q * (-r).ln_1p()
+
+    }else if q >= N {
+        let corr = stirling_series(q) - stirling_series(p + q);
+        log_gamma(p) + corr + p - p * (p + q).ln() + (q - 0.5) * (-r).ln_1p()
+
+    }else{
+        log_gamma(p) + log_gamma(q) - log_gamma(p + q)
+    }
+}
+
+/// Return the logarithm of the absolute value of the beta function and its sign, *(log |Β(a, b)|, sgn Β(a, b))*.
+/// 
+/// The sign is NaN if *a* or *b* is a pole of the gamma function (*0, -1, -2, ...*)
+/// and 1 if *Β(a, b) = 0*, that is, only *a + b* is a pole.
+/// 
+/// ```
+/// use comonjo_mathfn::log_beta_sign;
+/// 
+/// let (log_abs, sign) = log_beta_sign(-0.5, 1.);  // Β(-1/2, 1) = -2
+/// assert!((log_abs - 2_f64.ln()).abs() <= 1e-14);
+/// assert_eq!(sign, -1.);
+/// ```
+pub fn log_beta_sign(a: f64, b: f64) -> (f64, f64) {
+    if a > 0. && b > 0. { return (log_beta(a, b), 1.); }
+
+    let (log_gamma_a, sign_a) = log_gamma_sign(a);
+    let (log_gamma_b, sign_b) = log_gamma_sign(b);
+    let (log_gamma_ab, sign_ab) = log_gamma_sign(a + b);
+    if log_gamma_ab.is_infinite() && log_gamma_a.is_finite() && log_gamma_b.is_finite() {
+        return (f64::NEG_INFINITY, 1.);  // 1/Γ(a+b) = 0 at the poles
+    }
+
+    // Β(a, b) = Γ(a)/[Γ(a+b)/Γ(b)] for a < 0 < b, where log Γ(b) and log Γ(a+b) cancel each other for large b
+    let (log_gamma_neg, sign_neg, neg, pos) = if a > 0. { (log_gamma_b, sign_b, b, a) }else{ (log_gamma_a, sign_a, a, b) };
+    if pos > 0. && log_gamma_neg.is_finite() {
+        let ratio = gamma_delta_ratio(pos, neg);
+        if ratio.is_normal() {
+            return (log_gamma_neg - ratio.abs().ln(), sign_neg * ratio.signum());
+        }
+    }
+    (log_gamma_a + log_gamma_b - log_gamma_ab, sign_a * sign_b * sign_ab)
+}
+
 #[cfg(test)]
 use crate::test_util::*;
 #[cfg(test)]
 use std::f64::consts::PI;
+#[cfg(test)]
+use crate::gamma_fn::gamma;
 
 #[test]
 fn test_that_the_beta_function_diverges_if_the_one_of_the_arguments_is_zero_or_negative_integers(){
@@ -110,6 +185,7 @@ fn test_the_values_of_beta_at_non_finite_args(){
 }
 
 #[test]
+#[allow(clippy::redundant_closure)]
 fn test_the_beta_function_properties(){
     const DELTA: f64 = 1e-3;
     
@@ -148,4 +224,86 @@ fn test_the_beta_function_properties(){
             |x| beta(x, 1. - x), 
             |x| PI / (PI * x).sin())
         .filter(|x| !is_close_to_an_integer(x, DELTA)).assert();
+
+    should_the_same_mathfn(
+        "B(x, 1) = 1/x (for large negative x)",
+            |x| beta(x, 1.),
+            |x| 1. / x)
+        .filter(|x| !is_close_to_an_integer(x, DELTA))
+        .var0(|v| v.range(-300., -150.).end()).assert();
+
+    should_the_same_mathfn(
+        "B(x, 2) = 1/(x(x+1)) (for large negative x)",
+            |x| beta(x, 2.),
+            |x| 1. / (x * (x + 1.)))
+        .filter(|x| !is_close_to_an_integer(x, DELTA))
+        .var0(|v| v.range(-300., -150.).end()).assert();
+}
+
+#[test]
+fn test_the_values_of_beta_at_the_poles(){
+    assert_eq!(beta(0., 2.5), f64::INFINITY, "Β(+0, 5/2) = ∞");
+    assert_eq!(beta(-1.5, 0.), f64::INFINITY, "Β(-3/2, +0) = ∞");
+    assert!(beta(-0., 2.5).is_nan(), "Β(-0, 5/2) = NaN");
+    assert!(beta(-2., 0.5).is_nan(), "Β(-2, 1/2) = NaN");
+    assert!(beta(0., -1.).is_nan(), "Β(+0, -1) = NaN");
+}
+
+#[test]
+fn test_that_the_beta_function_vanishes_if_the_sum_of_the_arguments_is_a_pole(){
+    assert_eq!(beta(-0.5, -0.5), 0., "Β(-1/2, -1/2) = 0");
+    assert_eq!(beta(-1.5, 0.5), 0., "Β(-3/2, 1/2) = 0");
+    assert_eq!(beta(-2.25, -0.75), 0., "Β(-9/4, -3/4) = 0");
+}
+
+#[test]
+fn test_the_values_of_log_beta_at_the_specific_points(){
+    // computed with 40 significant digits
+    let values = [
+        (1000., 1000., -1388.4826016359023),
+        (1e10, 0.5, -10.940560522033028),
+        (0.5, 1e15, -16.69702325453064),
+        (1e-5, 1e5, 11.512804563691176),
+        (3.5, 2.25, -3.0408707193828155),
+        (123.456, 7.5, -28.778580864140646)];
+
+    for (a, b, exp) in values {
+        assert_approximately(log_beta(a, b), exp, 1e-14, &format!("log Β({}, {})", a, b));
+        assert_approximately(log_beta(b, a), exp, 1e-14, &format!("log Β({}, {})", b, a));
+    }
+
+    // the values by mpmath, where log Γ(b) and log Γ(a+b) cancel each other
+    let values = [((-0.5, 1e10), 12.778437588417374), ((-2.5, 1e6), 34.482528303408636), ((-10.5, 1e8), 178.26987661703197)];
+    assert_reference_values("log |Β(a, b)|", |(a, b)| log_beta_sign(a, b).0, &values, 1e-14);
+    assert_reference_values("sgn Β(a, b)", |(a, b)| log_beta_sign(a, b).1, &values.map(|(args, _)| (args, -1.)), EPS);
+
+    assert!(log_beta(-0.5, 1.).is_nan(), "log Β(-1/2, 1) = NaN");
+    assert_eq!(log_beta(f64::INFINITY, 1.), f64::NEG_INFINITY, "log Β(∞, 1) = -∞");
+}
+
+#[test]
+fn test_the_log_beta_function_properties(){
+    const DELTA: f64 = 1e-3;
+
+    should_the_same_mathfn2(
+        "log Β(a, b) = log Γ(a) + log Γ(b) - log Γ(a+b)",
+            log_beta,
+            |a, b| log_gamma(a) + log_gamma(b) - log_gamma(a + b))
+        .var0(|v| v.name("a").range(0.1, 100.).end())
+        .var1(|v| v.name("b").range(0.1, 100.).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "log Β(a, b+1) = log Β(a, b) + log(b/(a+b))",
+            |a, b| log_beta(a, b + 1.),
+            |a, b| log_beta(a, b) + (b / (a + b)).ln())
+        .var0(|v| v.name("a").range(0.1, 10.).end())
+        .var1(|v| v.name("b").range(1e3, 1e8).end()).assert();
+
+    should_the_same_mathfn2(
+        "sgn Β(x, y) exp(log|Β(x, y)|) = Γ(x)Γ(y)/Γ(x+y)",
+            |x, y| { let (log_abs, sign) = log_beta_sign(x, y); sign * log_abs.exp() },
+            |x, y| gamma(x) * gamma(y) / gamma(x + y))
+        .filter(|x, y| [x, y, x+y].iter().all(|t| !is_close_to_a_non_positive_integer(*t, DELTA)))
+        .epsilon(1e-11).assert();
 }
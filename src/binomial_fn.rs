@@ -0,0 +1,201 @@
+// The tests refer to
+// <a href="https://en.m.wikipedia.org/wiki/Binomial_coefficient">Binomial coefficient</a>
+// <a href="https://en.m.wikipedia.org/wiki/Multinomial_theorem">Multinomial theorem</a>
+
+use crate::beta_fn::{log_beta, log_beta_sign};
+
+/// Return the binomial coefficient *C(n, k) = n!/(k!(n-k)!)* as an integer,
+/// or `None` if it overflows u128.
+/// 
+/// ```
+/// use comonjo_mathfn::binomial_u128;
+/// 
+/// assert_eq!(binomial_u128(5, 2), Some(10));
+/// assert_eq!(binomial_u128(5, 6), Some(0));
+/// assert_eq!(binomial_u128(131, 65), Some(188694833082770476622296176145946360850));
+/// assert_eq!(binomial_u128(132, 66), None);
+/// ```
+pub fn binomial_u128(n: u64, k: u64) -> Option<u128> {
+    if k > n { return Some(0); }
+    let k = k.min(n - k) as u128;
+    let n = n as u128;
+
+    // C(n, i+1) = C(n, i)(n-i)/(i+1), where the division is done before the multiplication
+    // so that the intermediate value doesn't exceed the result
+    (0..k).try_fold(1_u128, |c, i| {
+        let g = gcd(c, i + 1);
+        (c / g).checked_mul((n - i) / ((i + 1) / g))
+    })
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Return the binomial coefficient *C(n, k) = 1/((n+1)Β(n-k+1, k+1))* generalized to real *n* and *k*.
+/// (The value is exact if *n* and *k* are integers and the value fits in u128.)
+/// 
+/// #### The value at the special points ####
+/// 
+/// ```
+/// use comonjo_mathfn::binomial;
+/// 
+/// fn assert_approximately(x: f64, y: f64){
+///     assert!((x - y).abs() <= 1e-14);
+/// }
+/// 
+/// assert_eq!(binomial(5., 2.), 10.);
+/// assert_eq!(binomial(5., 6.), 0.);
+/// assert_eq!(binomial(-1., 3.), -1.);
+/// assert_eq!(binomial(-3., -5.), 6.);
+/// assert_eq!(binomial(-3., -1.), 0.);
+/// 
+/// assert_approximately(binomial(0.5, 2.), -0.125);
+/// assert_approximately(binomial(-0.5, 2.), 0.375);
+/// ```
+pub fn binomial(n: f64, k: f64) -> f64 {
+    if n.is_nan() || k.is_nan() { return f64::NAN; }
+
+    if n.fract() == 0. && k.fract() == 0. {
+        if n >= 0. {
+            if k < 0. || k > n { return 0.; }
+            if n <= u64::MAX as f64 {
+                if let Some(c) = binomial_u128(n as u64, k as u64) { return c as f64; }
+            }
+        }else if k >= 0. {
+            let sign = if k % 2. == 0. { 1. }else{ -1. };
+            return sign * binomial(k - n - 1., k);
+        }else if k <= n {
+            let sign = if (n - k) % 2. == 0. { 1. }else{ -1. };
+            return sign * binomial(-k - 1., n - k);
+        }else{
+            return 0.;
+        }
+    }
+
+    let (log_abs, sign) = log_beta_sign(n - k + 1., k + 1.);
+    sign * (-log_abs).exp() / (n + 1.)
+}
+
+/// Return the logarithm of the binomial coefficient *log C(n, k)*.
+/// (The arguments must satisfy *n > -1* and *-1 < k < n+1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::log_binomial;
+/// 
+/// assert!((log_binomial(5., 2.) - 10_f64.ln()).abs() <= 1e-14);
+/// ```
+pub fn log_binomial(n: f64, k: f64) -> f64 {
+    -(n + 1.).ln() - log_beta(n - k + 1., k + 1.)
+}
+
+/// Return the multinomial coefficient *(k₁ + k₂ + ... + kₘ)!/(k₁!k₂!...kₘ!)*.
+/// 
+/// ```
+/// use comonjo_mathfn::multinomial;
+/// 
+/// assert_eq!(multinomial(&[2, 3, 4]), 1260.);
+/// assert_eq!(multinomial(&[]), 1.);
+/// ```
+pub fn multinomial(ks: &[u64]) -> f64 {
+    // (k₁ + ... + kₘ)!/(k₁!...kₘ!) = Π_i C(k₁ + ... + kᵢ, kᵢ)
+    let mut sum = 0.;
+    let mut result = 1.;
+    for &k in ks {
+        let k = k as f64;
+        sum += k;
+        result *= binomial(sum, k);
+    }
+    result
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+
+#[test]
+fn test_the_values_of_binomial_at_the_specific_points(){
+    use crate::factorial_fn::factorial;
+
+    for n in 0..=20 {
+        assert_eq!(binomial(n as f64, 0.), 1., "C(n, 0) = 1 at n = {}", n);
+        assert_eq!(binomial(n as f64, n as f64), 1., "C(n, n) = 1 at n = {}", n);
+        assert_eq!(binomial(-1., n as f64), if n % 2 == 0 { 1. }else{ -1. }, "C(-1, k) = (-1)ᵏ at k = {}", n);
+        assert_eq!(binomial(-1., -1. - n as f64), if n % 2 == 0 { 1. }else{ -1. }, "C(-1, -1-k) = (-1)ᵏ at k = {}", n);
+        assert_approximately(binomial(-0.5, n as f64),
+            (-0.25_f64).powi(n as i32) * factorial(2 * n) / factorial(n).powi(2), EPS,
+            &format!("C(-1/2, k) = (-1/4)ᵏ(2k)!/(k!)² at k = {}", n));
+    }
+
+    assert_eq!(binomial(1e15, 2.), 1e15 * (1e15 - 1.) / 2., "C(n, 2) = n(n-1)/2");
+    assert_eq!(binomial(5., -1.), 0., "C(5, -1) = 0");
+    assert!(binomial(f64::NAN, 1.).is_nan(), "C(NaN, 1) = NaN");
+}
+
+#[test]
+fn test_the_overflow_of_binomial_u128(){
+    assert!(binomial_u128(131, 65).is_some(), "C(131, 65) doesn't overflow u128");
+    assert_eq!(binomial_u128(132, 66), None, "C(132, 66) overflows u128");
+    assert_eq!(binomial_u128(132, 65), None, "C(132, 65) overflows u128");
+    assert_eq!(binomial_u128(u64::MAX, 1), Some(u64::MAX as u128), "C(n, 1) = n");
+    assert_eq!(binomial_u128(u64::MAX, 3), None, "C(2⁶⁴-1, 3) overflows u128");
+}
+
+#[test]
+fn test_the_binomial_function_properties(){
+    should_the_same_mathfn2(
+        "C(n, k) (u128) = C(n, k) (f64)",
+            |n, k| binomial_u128(n as u64, k as u64).unwrap() as f64,
+            binomial)
+        .var0(|v| v.name("n").range(0., 60.).is_integer(true).end())
+        .var1(|v| v.name("k").range(0., 60.).is_integer(true).end()).assert();
+
+    should_the_same_mathfn2(
+        "C(n, k) = C(n-1, k-1) + C(n-1, k)",
+            binomial,
+            |n, k| binomial(n - 1., k - 1.) + binomial(n - 1., k))
+        .filter(|n, k| k < n - 1.)
+        .var0(|v| v.name("n").range(1., 30.).end())
+        .var1(|v| v.name("k").range(1., 30.).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "C(n, k) = (-1)ᵏC(k-n-1, k)",
+            binomial,
+            |n, k| (-1_f64).powi(k as i32) * binomial(k - n - 1., k))
+        .var0(|v| v.name("n").range(-10., -0.5).end())
+        .var1(|v| v.name("k").range(0., 10.).is_integer(true).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "log C(n, k) = log(C(n, k))",
+            log_binomial,
+            |n, k| binomial(n, k).ln())
+        .filter(|n, k| k < n)
+        .var0(|v| v.name("n").range(0., 100.).end())
+        .var1(|v| v.name("k").range(0., 100.).end()).assert();
+
+    should_the_same_mathfn2(
+        "log C(n, k) = log C(n, n-k)",
+            log_binomial,
+            |n, k| log_binomial(n, n - k))
+        .filter(|n, k| k < n)
+        .var0(|v| v.name("n").range(0., 1e8).end())
+        .var1(|v| v.name("k").range(0., 1e8).end()).assert();
+}
+
+#[test]
+fn test_the_multinomial_function_properties(){
+    should_the_same_mathfn2(
+        "(k₁+k₂)!/(k₁!k₂!) = C(k₁+k₂, k₁)",
+            |k1, k2| multinomial(&[k1 as u64, k2 as u64]),
+            |k1, k2| binomial(k1 + k2, k1))
+        .var0(|v| v.name("k₁").range(0., 30.).is_integer(true).end())
+        .var1(|v| v.name("k₂").range(0., 30.).is_integer(true).end()).assert();
+
+    assert_eq!(multinomial(&[5]), 1., "k!/k! = 1");
+    assert_eq!(multinomial(&[1; 10]), 3628800., "10!/(1!)¹⁰ = 10!");
+    assert_eq!(multinomial(&[0, 3, 0]), 1., "3!/(0!3!0!) = 1");
+}
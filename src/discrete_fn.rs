@@ -0,0 +1,567 @@
+// The tests refer to
+// <a href="https://en.wikipedia.org/wiki/Poisson_distribution">Poisson distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Binomial_distribution">Binomial distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Negative_binomial_distribution">Negative binomial distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Hypergeometric_distribution">Hypergeometric distribution</a>
+
+use crate::beta_fn::log_beta;
+use crate::gamma_fn::log_gamma;
+use crate::ibeta_fn::p_beta_normalizable_xy;
+use crate::igamma_fn::{p_gamma, q_gamma, log_power_exp_by_gamma};
+
+fn is_count(k: f64) -> bool {
+    k >= 0. && k.fract() == 0.
+}
+
+/// Return *log(λ^k e^{-λ}/k!)* accurately even if *k* and *λ* are large.
+/// (*k* is not necessarily an integer, where *k! = Γ(k+1)*.)
+fn log_poisson_term(k: f64, lambda: f64) -> f64 {
+    if lambda == 0. { return if k == 0. { 0. }else{ f64::NEG_INFINITY }; }
+    log_power_exp_by_gamma(k + 1., lambda, log_gamma(k + 1.)) - lambda.ln()
+}
+
+//***** Poisson distribution *****
+fn is_out_of_domain_of_poisson(k: f64, lambda: f64) -> bool {
+    k.is_nan() || lambda.is_nan() || lambda < 0. || lambda.is_infinite()
+}
+
+/// Return the logarithm of the probability mass function of the Poisson distribution with the mean *λ*,
+/// that is, *log(λ^k e^{-λ}/k!)*. (The value is -∞ unless *k* is a non-negative integer.)
+///
+/// ```
+/// use comonjo_mathfn::log_poisson_pmf;
+///
+/// assert!((log_poisson_pmf(1000., 3.5) - (-4662.865209992795)).abs() <= 1e-9);
+/// assert_eq!(log_poisson_pmf(0.5, 3.), f64::NEG_INFINITY);
+/// ```
+pub fn log_poisson_pmf(k: f64, lambda: f64) -> f64 {
+    if is_out_of_domain_of_poisson(k, lambda) { return f64::NAN; }
+    if !is_count(k) || k.is_infinite() { return f64::NEG_INFINITY; }
+
+    log_poisson_term(k, lambda)
+}
+
+/// Return a value of the probability mass function of the Poisson distribution with the mean *λ*,
+/// that is, *λ^k e^{-λ}/k!*. (The value is 0 unless *k* is a non-negative integer.)
+///
+/// The value is evaluated in log space to avoid the overflow of *λ^k* and *k!*.
+///
+/// ```
+/// use comonjo_mathfn::poisson_pmf;
+///
+/// assert!((poisson_pmf(2., 3.) - 4.5 * (-3_f64).exp()).abs() <= 1e-15);
+/// assert!((poisson_pmf(1000., 1000.) - 0.012614611348721499).abs() <= 1e-15);
+/// assert_eq!(poisson_pmf(0.5, 3.), 0.);
+/// ```
+pub fn poisson_pmf(k: f64, lambda: f64) -> f64 {
+    log_poisson_pmf(k, lambda).exp()
+}
+
+/// Return a value of the lower CDF *P(X ≤ k)* of the Poisson distribution with the mean *λ*,
+/// that is, *Q(⌊k⌋+1, λ)*.
+///
+/// ```
+/// use comonjo_mathfn::{p_poisson, poisson_pmf};
+///
+/// assert!((p_poisson(10., 3.5) - 0.998980605562383).abs() <= 1e-15);
+/// assert_eq!(p_poisson(-1., 3.5), 0.);
+/// assert!((p_poisson(0., 3.5) - poisson_pmf(0., 3.5)).abs() <= 1e-16);
+/// ```
+pub fn p_poisson(k: f64, lambda: f64) -> f64 {
+    if is_out_of_domain_of_poisson(k, lambda) { return f64::NAN; }
+    if k < 0. { return 0.; }
+    if k.is_infinite() { return 1.; }
+
+    q_gamma(k.floor() + 1., lambda)
+}
+
+/// Return a value of the upper CDF *P(X > k)* of the Poisson distribution with the mean *λ*,
+/// that is, *P(⌊k⌋+1, λ)*.
+///
+/// ```
+/// use comonjo_mathfn::q_poisson;
+///
+/// assert!((q_poisson(10., 3.5) - 0.001019394437617005).abs() <= 1e-15);
+/// ```
+pub fn q_poisson(k: f64, lambda: f64) -> f64 {
+    if is_out_of_domain_of_poisson(k, lambda) { return f64::NAN; }
+    if k < 0. { return 1.; }
+    if k.is_infinite() { return 0.; }
+
+    p_gamma(k.floor() + 1., lambda)
+}
+
+//***** binomial distribution *****
+fn is_out_of_domain_of_binomial(k: f64, n: f64, p: f64) -> bool {
+    k.is_nan() || !is_count(n) || n.is_infinite() || p.is_nan() || !(0. ..=1.).contains(&p)
+}
+
+/// Return the logarithm of the probability mass function of the binomial distribution, *log(C(n, k)p^k(1-p)^{n-k})*.
+///
+/// Ref: C. Loader, Fast and Accurate Computation of Binomial Probabilities (2000)
+///
+/// ```
+/// use comonjo_mathfn::log_binomial_pmf;
+///
+/// assert!((log_binomial_pmf(900., 1000., 0.3) - (-797.3297308539511)).abs() <= 1e-10);
+/// assert_eq!(log_binomial_pmf(6., 5., 0.3), f64::NEG_INFINITY);
+/// ```
+pub fn log_binomial_pmf(k: f64, n: f64, p: f64) -> f64 {
+    if is_out_of_domain_of_binomial(k, n, p) { return f64::NAN; }
+    if !is_count(k) || k > n { return f64::NEG_INFINITY; }
+
+    log_poisson_term(k, n * p) + log_poisson_term(n - k, n * (1. - p)) - log_poisson_term(n, n)
+}
+
+/// Return a value of the probability mass function of the binomial distribution
+/// with *n* trials and the success probability *p*, that is, *C(n, k)p^k(1-p)^{n-k}*.
+/// (The value is 0 unless *k* is an integer in *[0, n]*.)
+///
+/// ```
+/// use comonjo_mathfn::binomial_pmf;
+///
+/// assert!((binomial_pmf(2., 5., 0.3) - 10. * 0.09 * 0.343).abs() <= 1e-15);
+/// assert!((binomial_pmf(300., 1000., 0.3) - 0.02752100382126686).abs() <= 1e-14);
+/// ```
+pub fn binomial_pmf(k: f64, n: f64, p: f64) -> f64 {
+    log_binomial_pmf(k, n, p).exp()
+}
+
+/// Return a value of the lower CDF *P(X ≤ k)* of the binomial distribution
+/// with *n* trials and the success probability *p*, that is, *I_{1-p}(n-k, k+1)* (*k = ⌊k⌋*).
+///
+/// ```
+/// use comonjo_mathfn::p_binomial;
+///
+/// assert!((p_binomial(7., 20., 0.3) - 0.7722717974181595).abs() <= 1e-15);
+/// assert_eq!(p_binomial(20., 20., 0.3), 1.);
+/// ```
+pub fn p_binomial(k: f64, n: f64, p: f64) -> f64 {
+    if is_out_of_domain_of_binomial(k, n, p) { return f64::NAN; }
+    if k < 0. { return 0.; }
+    if k >= n { return 1.; }
+
+    let k = k.floor();
+    p_beta_normalizable_xy(n - k, k + 1., 1. - p, p, log_beta(n - k, k + 1.))
+}
+
+/// Return a value of the upper CDF *P(X > k)* of the binomial distribution
+/// with *n* trials and the success probability *p*, that is, *I_p(k+1, n-k)* (*k = ⌊k⌋*).
+///
+/// ```
+/// use comonjo_mathfn::q_binomial;
+///
+/// assert!((q_binomial(7., 20., 0.3) - 0.22772820258184043).abs() <= 1e-15);
+/// ```
+pub fn q_binomial(k: f64, n: f64, p: f64) -> f64 {
+    if is_out_of_domain_of_binomial(k, n, p) { return f64::NAN; }
+    if k < 0. { return 1.; }
+    if k >= n { return 0.; }
+
+    let k = k.floor();
+    p_beta_normalizable_xy(k + 1., n - k, p, 1. - p, log_beta(k + 1., n - k))
+}
+
+//***** negative binomial distribution *****
+fn is_out_of_domain_of_negbinomial(k: f64, r: f64, p: f64) -> bool {
+    k.is_nan() || r.is_nan() || r <= 0. || r.is_infinite() || p.is_nan() || !(0. ..=1.).contains(&p)
+}
+
+/// Return the logarithm of the probability mass function of the negative binomial distribution, *log(C(k+r-1, k)p^r(1-p)^k)*.
+///
+/// ```
+/// use comonjo_mathfn::log_negbinomial_pmf;
+///
+/// assert!((log_negbinomial_pmf(1000., 2.5, 0.4) - (-503.0375267965052)).abs() <= 1e-10);
+/// assert_eq!(log_negbinomial_pmf(0.5, 2.5, 0.4), f64::NEG_INFINITY);
+/// ```
+pub fn log_negbinomial_pmf(k: f64, r: f64, p: f64) -> f64 {
+    if is_out_of_domain_of_negbinomial(k, r, p) { return f64::NAN; }
+    if !is_count(k) || k.is_infinite() { return f64::NEG_INFINITY; }
+
+    let n = k + r;
+    (r / n).ln() + log_poisson_term(r, n * p) + log_poisson_term(k, n * (1. - p)) - log_poisson_term(n, n)
+}
+
+/// Return a value of the probability mass function of the negative binomial distribution,
+/// the number of failures before the *r*-th success with the success probability *p*,
+/// that is, *C(k+r-1, k)p^r(1-p)^k*. (The value is 0 unless *k* is a non-negative integer.)
+///
+/// ```
+/// use comonjo_mathfn::negbinomial_pmf;
+///
+/// assert!((negbinomial_pmf(2., 3., 0.4) - 6. * 0.064 * 0.36).abs() <= 1e-15);
+/// assert!((negbinomial_pmf(12., 2.5, 0.4) - 0.007988391614154496).abs() <= 1e-15);
+/// ```
+pub fn negbinomial_pmf(k: f64, r: f64, p: f64) -> f64 {
+    log_negbinomial_pmf(k, r, p).exp()
+}
+
+/// Return a value of the lower CDF *P(X ≤ k)* of the negative binomial distribution,
+/// that is, *I_p(r, k+1)* (*k = ⌊k⌋*).
+///
+/// ```
+/// use comonjo_mathfn::p_negbinomial;
+///
+/// assert!((p_negbinomial(5., 2.5, 0.4) - 0.7664449047370959).abs() <= 1e-15);
+/// ```
+pub fn p_negbinomial(k: f64, r: f64, p: f64) -> f64 {
+    if is_out_of_domain_of_negbinomial(k, r, p) { return f64::NAN; }
+    if k < 0. { return 0.; }
+    if k.is_infinite() { return 1.; }
+
+    let k = k.floor();
+    p_beta_normalizable_xy(r, k + 1., p, 1. - p, log_beta(r, k + 1.))
+}
+
+/// Return a value of the upper CDF *P(X > k)* of the negative binomial distribution,
+/// that is, *I_{1-p}(k+1, r)* (*k = ⌊k⌋*).
+///
+/// ```
+/// use comonjo_mathfn::q_negbinomial;
+///
+/// assert!((q_negbinomial(5., 2.5, 0.4) - 0.23355509526290408).abs() <= 1e-15);
+/// ```
+pub fn q_negbinomial(k: f64, r: f64, p: f64) -> f64 {
+    if is_out_of_domain_of_negbinomial(k, r, p) { return f64::NAN; }
+    if k < 0. { return 1.; }
+    if k.is_infinite() { return 0.; }
+
+    let k = k.floor();
+    p_beta_normalizable_xy(k + 1., r, 1. - p, p, log_beta(k + 1., r))
+}
+
+//***** hypergeometric distribution *****
+fn is_out_of_domain_of_hypergeometric(k: f64, population: f64, successes: f64, draws: f64) -> bool {
+    k.is_nan() || !is_count(population) || population.is_infinite()
+        || !is_count(successes) || successes > population || !is_count(draws) || draws > population
+}
+
+/// Return the logarithm of the probability mass function of the hypergeometric distribution
+/// by the binomial probabilities with *p = n/N*. (*k* must be an integer in the support.)
+pub(crate) fn log_hypergeometric_pmf(k: f64, population: f64, successes: f64, draws: f64) -> f64 {
+    let (nn, kk, n) = (population, successes, draws);
+    if nn == 0. { return 0.; }  // the only point k = 0
+
+    let p = n / nn;
+    let q = 1. - p;
+    log_poisson_term(k, kk * p) + log_poisson_term(kk - k, kk * q)
+        + log_poisson_term(n - k, (nn - kk) * p) + log_poisson_term(nn - kk - n + k, (nn - kk) * q)
+        - log_poisson_term(n, n) - log_poisson_term(nn - n, nn - n)
+        - log_poisson_term(kk, kk) - log_poisson_term(nn - kk, nn - kk) + log_poisson_term(nn, nn)
+}
+
+/// Return *[max(0, n+K-N), min(n, K)]*, the support of the hypergeometric distribution.
+pub(crate) fn support_of_hypergeometric(population: f64, successes: f64, draws: f64) -> (f64, f64) {
+    ((draws + successes - population).max(0.), draws.min(successes))
+}
+
+/// Return a value of the probability mass function of the hypergeometric distribution, *C(K, k)C(N-K, n-k)/C(N, n)*,
+/// for *n* draws from a population of *N* with *K* successes.
+///
+/// ```
+/// use comonjo_mathfn::hypergeometric_pmf;
+///
+/// assert!((hypergeometric_pmf(5., 50., 10., 20.) - 0.21508500718492518).abs() <= 1e-15);
+/// assert!((hypergeometric_pmf(1000., 1e6, 1e4, 1e5) - 0.013363943066541633).abs() <= 1e-15);
+/// assert_eq!(hypergeometric_pmf(11., 50., 10., 20.), 0.);
+/// ```
+pub fn hypergeometric_pmf(k: f64, population: f64, successes: f64, draws: f64) -> f64 {
+    if is_out_of_domain_of_hypergeometric(k, population, successes, draws) { return f64::NAN; }
+    let (lo, hi) = support_of_hypergeometric(population, successes, draws);
+    if k.fract() != 0. || k < lo || k > hi { return 0.; }
+
+    log_hypergeometric_pmf(k, population, successes, draws).exp()
+}
+
+/// Return *P(X ≤ k)* for an integer *k* in the support not greater than the mean.
+///
+/// The terms are summed downward from *k* by the ratio
+/// *π(j-1)/π(j) = j(N-K-n+j)/((K-j+1)(n-j+1))*, which decreases away from the mean.
+fn lower_sum_of_hypergeometric(k: f64, population: f64, successes: f64, draws: f64) -> f64 {
+    let (nn, kk, n) = (population, successes, draws);
+    let (lo, _) = support_of_hypergeometric(nn, kk, n);
+    let mut sum = 1.;
+    let mut term = 1.;
+    let mut j = k;
+    while j > lo {
+        term *= j * (nn - kk - n + j) / ((kk - j + 1.) * (n - j + 1.));
+        sum += term;
+        if term <= f64::EPSILON * sum { break; }
+        j -= 1.;
+    }
+    log_hypergeometric_pmf(k, nn, kk, n).exp() * sum
+}
+
+/// Return *P(X ≤ k)* (`upper` is false) or *P(X > k)* (`upper` is true) of the hypergeometric distribution.
+fn hypergeometric_cdfs(k: f64, population: f64, successes: f64, draws: f64, upper: bool) -> f64 {
+    let (lo, hi) = support_of_hypergeometric(population, successes, draws);
+    let k = k.floor();
+    if k < lo { return if upper { 1. }else{ 0. }; }
+    if k >= hi { return if upper { 0. }else{ 1. }; }
+
+    // the tail below the mean is summed directly, and the other tail is that of the failures, n-X
+    if k * population <= draws * successes {
+        let p = lower_sum_of_hypergeometric(k, population, successes, draws);
+        if upper { 1. - p }else{ p }
+    }else{
+        let q = lower_sum_of_hypergeometric(draws - k - 1., population, population - successes, draws);
+        if upper { q }else{ 1. - q }
+    }
+}
+
+/// Return a value of the lower CDF *P(X ≤ k)* of the hypergeometric distribution
+/// with the population *N*, the successes *K* and the draws *n*.
+///
+/// ```
+/// use comonjo_mathfn::p_hypergeometric;
+///
+/// assert!((p_hypergeometric(5., 50., 10., 20.) - 0.8601118970671333).abs() <= 1e-15);
+/// assert!((p_hypergeometric(900., 1e6, 1e4, 1e5) - 0.0003627423539167798).abs() <= 1e-17);
+/// ```
+pub fn p_hypergeometric(k: f64, population: f64, successes: f64, draws: f64) -> f64 {
+    if is_out_of_domain_of_hypergeometric(k, population, successes, draws) { return f64::NAN; }
+    hypergeometric_cdfs(k, population, successes, draws, false)
+}
+
+/// Return a value of the upper CDF *P(X > k)* of the hypergeometric distribution
+/// with the population *N*, the successes *K* and the draws *n*.
+///
+/// ```
+/// use comonjo_mathfn::q_hypergeometric;
+///
+/// assert!((q_hypergeometric(5., 50., 10., 20.) - 0.13988810293286674).abs() <= 1e-15);
+/// assert!((q_hypergeometric(1100., 1e6, 1e4, 1e5) - 0.00044433163312031584).abs() <= 1e-17);
+/// ```
+pub fn q_hypergeometric(k: f64, population: f64, successes: f64, draws: f64) -> f64 {
+    if is_out_of_domain_of_hypergeometric(k, population, successes, draws) { return f64::NAN; }
+    hypergeometric_cdfs(k, population, successes, draws, true)
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::binomial_fn::binomial;
+
+#[test]
+fn test_the_values_of_discrete_distributions_out_of_the_domain(){
+    for (k, lambda) in non_finite_args2_with(1., [-1., 0., 2.]){
+        if !k.is_nan() && lambda >= 0. && lambda.is_finite() { continue; }
+        assert!(poisson_pmf(k, lambda).is_nan(), "poisson_pmf({}, {}) = NaN", k, lambda);
+        assert!(p_poisson(k, lambda).is_nan(), "p_poisson({}, {}) = NaN", k, lambda);
+        assert!(q_poisson(k, lambda).is_nan(), "q_poisson({}, {}) = NaN", k, lambda);
+    }
+
+    for (n, p) in non_finite_args2_with([-1., 2.5, 3.], [-0.5, 0.5, 1.5]){
+        if n == 3. && (0. ..=1.).contains(&p) { continue; }
+        assert!(binomial_pmf(1., n, p).is_nan(), "binomial_pmf(1, {}, {}) = NaN", n, p);
+        assert!(p_binomial(1., n, p).is_nan(), "p_binomial(1, {}, {}) = NaN", n, p);
+        assert!(q_binomial(1., n, p).is_nan(), "q_binomial(1, {}, {}) = NaN", n, p);
+    }
+
+    for (r, p) in non_finite_args2_with([-1., 0., 2.5], [-0.5, 0.5, 1.5]){
+        if r == 2.5 && (0. ..=1.).contains(&p) { continue; }
+        assert!(negbinomial_pmf(1., r, p).is_nan(), "negbinomial_pmf(1, {}, {}) = NaN", r, p);
+        assert!(p_negbinomial(1., r, p).is_nan(), "p_negbinomial(1, {}, {}) = NaN", r, p);
+        assert!(q_negbinomial(1., r, p).is_nan(), "q_negbinomial(1, {}, {}) = NaN", r, p);
+    }
+}
+
+#[test]
+fn test_the_poisson_distribution_properties(){
+    should_the_same_mathfn2(
+        "poisson_pmf(k, λ) = λ^k e^{-λ}/k!",
+            poisson_pmf,
+            |k, lambda| lambda.powf(k) * (-lambda).exp() / (1..=k as u32).map(|j| j as f64).product::<f64>())
+        .var0(|v| v.name("k").range(0., 30.).is_integer(true).end())
+        .var1(|v| v.name("λ").range(0., 30.).end()).assert();
+
+    should_the_same_mathfn2(
+        "p_poisson(k, λ) = Σ_{j=0}^k poisson_pmf(j, λ)",
+            p_poisson,
+            |k, lambda| (0..=k as u32).map(|j| poisson_pmf(j as f64, lambda)).sum())
+        .var0(|v| v.name("k").range(0., 40.).is_integer(true).end())
+        .var1(|v| v.name("λ").range(0., 30.).end()).assert();
+
+    should_the_same_mathfn2(
+        "q_poisson(k, λ) = 1 - p_poisson(k, λ)",
+            q_poisson,
+            |k, lambda| 1. - p_poisson(k, lambda))
+        .var0(|v| v.name("k").range(-1., 40.).end())
+        .var1(|v| v.name("λ").range(0., 30.).end()).assert();
+
+    should_the_same_mathfn2(
+        "p_poisson(k, λ) = p_poisson(⌊k⌋, λ)",
+            p_poisson,
+            |k, lambda| p_poisson(k.floor(), lambda))
+        .var0(|v| v.name("k").range(0., 40.).is_integer(false).end())
+        .var1(|v| v.name("λ").range(0., 30.).end()).assert();
+}
+
+#[test]
+fn test_the_binomial_distribution_properties(){
+    should_the_same_mathfn3(
+        "binomial_pmf(k, n, p) = C(n, k)p^k(1-p)^{n-k}",
+            binomial_pmf,
+            |k, n, p| binomial(n, k) * p.powf(k) * (1. - p).powf(n - k))
+        .filter(|k, n, _| k <= n)
+        .var0(|v| v.name("k").range(0., 30.).is_integer(true).n(20).end())
+        .var1(|v| v.name("n").range(0., 30.).is_integer(true).n(20).end())
+        .var2(|v| v.name("p").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_binomial(k, n, p) = Σ_{j=0}^k binomial_pmf(j, n, p)",
+            p_binomial,
+            |k, n, p| (0..=k as u32).map(|j| binomial_pmf(j as f64, n, p)).sum())
+        .var0(|v| v.name("k").range(0., 40.).is_integer(true).n(20).end())
+        .var1(|v| v.name("n").range(0., 40.).is_integer(true).n(20).end())
+        .var2(|v| v.name("p").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_binomial(k, n, p) = 1 - p_binomial(k, n, p)",
+            q_binomial,
+            |k, n, p| 1. - p_binomial(k, n, p))
+        .var0(|v| v.name("k").range(-1., 40.).n(20).end())
+        .var1(|v| v.name("n").range(0., 40.).is_integer(true).n(20).end())
+        .var2(|v| v.name("p").range(0., 1.).n(20).end()).assert();
+
+    // the binomial distribution approaches the Poisson distribution as n → ∞ with np = λ
+    should_the_same_mathfn2(
+        "p_binomial(k, n, λ/n) → p_poisson(k, λ)",
+            |k, lambda| p_binomial(k, 1e9, lambda / 1e9),
+            p_poisson)
+        .var0(|v| v.name("k").range(0., 20.).is_integer(true).end())
+        .var1(|v| v.name("λ").range(0.1, 10.).end())
+        .epsilon(1e-7).assert();
+}
+
+#[test]
+fn test_the_negative_binomial_distribution_properties(){
+    should_the_same_mathfn3(
+        "negbinomial_pmf(k, r, p) = C(k+r-1, k)p^r(1-p)^k",
+            negbinomial_pmf,
+            |k, r, p| binomial(k + r - 1., k) * p.powf(r) * (1. - p).powf(k))
+        .var0(|v| v.name("k").range(0., 30.).is_integer(true).n(20).end())
+        .var1(|v| v.name("r").range(1., 20.).is_integer(true).n(20).end())
+        .var2(|v| v.name("p").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_negbinomial(k, r, p) = Σ_{j=0}^k negbinomial_pmf(j, r, p)",
+            p_negbinomial,
+            |k, r, p| (0..=k as u32).map(|j| negbinomial_pmf(j as f64, r, p)).sum())
+        .var0(|v| v.name("k").range(0., 40.).is_integer(true).n(20).end())
+        .var1(|v| v.name("r").range(0.5, 20.).n(20).end())
+        .var2(|v| v.name("p").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_negbinomial(k, r, p) = 1 - p_negbinomial(k, r, p)",
+            q_negbinomial,
+            |k, r, p| 1. - p_negbinomial(k, r, p))
+        .var0(|v| v.name("k").range(-1., 40.).n(20).end())
+        .var1(|v| v.name("r").range(0.5, 20.).n(20).end())
+        .var2(|v| v.name("p").range(0., 1.).n(20).end()).assert();
+
+    // the geometric distribution: P(X ≤ k) = 1 - (1-p)^{k+1}
+    should_the_same_mathfn2(
+        "p_negbinomial(k, 1, p) = 1 - (1-p)^{k+1}",
+            |k, p| p_negbinomial(k, 1., p),
+            |k, p| 1. - (1. - p).powf(k + 1.))
+        .var0(|v| v.name("k").range(0., 40.).is_integer(true).end())
+        .var1(|v| v.name("p").range(0., 1.).end()).assert();
+}
+
+#[test]
+fn test_the_discrete_distributions_with_large_parameters(){
+    // the values by mpmath near the mean
+    let values = [
+        ((1e9, 1e9), 0.5000084104417389),
+        ((1000094868., 1e9), 0.9986499389557975),
+        ((1e12, 1e12), 0.5000002659615203),
+        ((1000003000000., 1e12), 0.998650098275164),
+    ];
+    assert_reference_values("p_poisson", |(k, lambda)| p_poisson(k, lambda), &values, EPS);
+    assert_reference_values("q_poisson", |(k, lambda)| q_poisson(k, lambda), &values.map(|(args, p)| (args, 1. - p)), EPS);
+
+    // the values by mpmath, where the PMFs underflow or log Γ cancel each other
+    assert_reference_values("log_poisson_pmf", |(k, lambda)| log_poisson_pmf(k, lambda), &[
+        ((1000., 3.5),  -4662.865209992795),
+        ((1e10,  1e10), -12.431863998183234),
+        ((3.,    1e-3), -22.516025306174466)], EPS);
+    assert_reference_values("log_binomial_pmf", |(k, n, p)| log_binomial_pmf(k, n, p), &[
+        ((900., 1000., 0.3), -797.3297308539511),
+        ((5e9,  1e10,  0.5), -11.738716817639956),
+        ((3.,   1e10,  1e-9), -4.884004192545918)], EPS);
+    assert_reference_values("log_negbinomial_pmf", |(k, r, p)| log_negbinomial_pmf(k, r, p), &[
+        ((1000., 2.5,   0.4), -503.0375267965052),
+        ((1e10,  1e10,  0.5), -12.778437588467375),
+        ((2.,    0.001, 0.3), -8.31445681989079)], EPS);
+
+    // the rounding errors of the continued fraction accumulate as n grows
+    for (n, expected, eps) in [(1e6, 0.5003989421806659, 1e-12), (1e9, 0.500012615662607, 1e-11), (1e10, 0.5000039894228039, 1e-10)]{
+        assert_approximately(p_binomial(0.5 * n, n, 0.5), expected, eps, &format!("p_binomial({}, {}, 1/2)", 0.5 * n, n));
+    }
+    for (r, expected, eps) in [(1e6, 0.500282094756512, 1e-12), (1e9, 0.5000089206205796, 1e-11), (1e10, 0.5000028209479177, 1e-10)]{
+        assert_approximately(p_negbinomial(r, r, 0.5), expected, eps, &format!("p_negbinomial({}, {}, 1/2)", r, r));
+    }
+}
+
+#[test]
+fn test_the_hypergeometric_distribution_properties(){
+    for (kk, n) in non_finite_args2_with([-1., 2.5, 30., 50.], [-1., 2.5, 10., 50.]){
+        let nn = 40.;
+        if is_count(kk) && kk <= nn && is_count(n) && n <= nn { continue; }
+        assert!(hypergeometric_pmf(1., nn, kk, n).is_nan(), "hypergeometric_pmf(1, {}, {}, {}) = NaN", nn, kk, n);
+        assert!(p_hypergeometric(1., nn, kk, n).is_nan(), "p_hypergeometric(1, {}, {}, {}) = NaN", nn, kk, n);
+        assert!(q_hypergeometric(1., nn, kk, n).is_nan(), "q_hypergeometric(1, {}, {}, {}) = NaN", nn, kk, n);
+    }
+    assert!(hypergeometric_pmf(1., f64::INFINITY, 10., 10.).is_nan());
+    assert!(p_hypergeometric(f64::NAN, 40., 10., 10.).is_nan());
+
+    should_the_same_mathfn3(
+        "hypergeometric_pmf(k, 40, K, n) = C(K, k)C(40-K, n-k)/C(40, n)",
+            |k, kk, n| hypergeometric_pmf(k, 40., kk, n),
+            |k, kk, n| binomial(kk, k) * binomial(40. - kk, n - k) / binomial(40., n))
+        .var0(|v| v.name("k").range(0., 40.).is_integer(true).n(20).end())
+        .var1(|v| v.name("K").range(0., 40.).is_integer(true).n(20).end())
+        .var2(|v| v.name("n").range(0., 40.).is_integer(true).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_hypergeometric(k, 40, K, n) = Σ_{j=0}^k hypergeometric_pmf(j, 40, K, n)",
+            |k, kk, n| p_hypergeometric(k, 40., kk, n),
+            |k, kk, n| (0..=k as u32).map(|j| hypergeometric_pmf(j as f64, 40., kk, n)).sum())
+        .var0(|v| v.name("k").range(0., 40.).is_integer(true).n(20).end())
+        .var1(|v| v.name("K").range(0., 40.).is_integer(true).n(20).end())
+        .var2(|v| v.name("n").range(0., 40.).is_integer(true).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_hypergeometric(k, 40, K, n) = 1 - p_hypergeometric(k, 40, K, n)",
+            |k, kk, n| q_hypergeometric(k, 40., kk, n),
+            |k, kk, n| 1. - p_hypergeometric(k, 40., kk, n))
+        .var0(|v| v.name("k").range(-1., 41.).n(20).end())
+        .var1(|v| v.name("K").range(0., 40.).is_integer(true).n(20).end())
+        .var2(|v| v.name("n").range(0., 40.).is_integer(true).n(20).end()).assert();
+
+    // the values for the large population by mpmath
+    let table = [
+        ((960., 1e6, 1e4, 1e5), 0.005484635539953505, 0.09230663252132545, 0.9076933674786746),
+        ((1040., 1e6, 1e4, 1e5), 0.005407665918728028, 0.9120075917586854, 0.0879924082413146),
+        ((150500., 1e6, 5e5, 3e5), 0.00016098927142229135, 0.9855321815719065, 0.014467818428093538),
+        ((33., 1e6, 37., 999000.), 6.352606401961872e-08, 6.394632079494736e-08, 0.9999999360536792)];
+    assert_reference_values("hypergeometric_pmf", |(k, nn, kk, n)| hypergeometric_pmf(k, nn, kk, n), &table.map(|(args, pmf, _, _)| (args, pmf)), EPS);
+    assert_reference_values("p_hypergeometric", |(k, nn, kk, n)| p_hypergeometric(k, nn, kk, n), &table.map(|(args, _, p, _)| (args, p)), EPS);
+    assert_reference_values("q_hypergeometric", |(k, nn, kk, n)| q_hypergeometric(k, nn, kk, n), &table.map(|(args, _, _, q)| (args, q)), EPS);
+
+    // the hypergeometric distribution approaches the binomial distribution as N → ∞ with K/N = p
+    should_the_same_mathfn2(
+        "p_hypergeometric(k, N, Np, n) → p_binomial(k, n, p)",
+            |k, p| p_hypergeometric(k, 1e12, (1e12 * p).round(), 30.),
+            |k, p| p_binomial(k, 30., p))
+        .var0(|v| v.name("k").range(0., 30.).is_integer(true).end())
+        .var1(|v| v.name("p").range(0.01, 0.99).end())
+        .epsilon(1e-9).assert();
+
+    // the empty population has only the point 0
+    assert_eq!(hypergeometric_pmf(0., 0., 0., 0.), 1.);
+    assert_eq!(hypergeometric_pmf(1., 0., 0., 0.), 0.);
+    assert_eq!((p_hypergeometric(0., 0., 0., 0.), q_hypergeometric(0., 0., 0., 0.)), (1., 0.));
+}
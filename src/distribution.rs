@@ -0,0 +1,1062 @@
+// The tests refer to
+// <a href="https://en.wikipedia.org/wiki/Normal_distribution">Normal distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Chi-squared_distribution">Chi-squared distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Gamma_distribution">Gamma distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Beta_distribution">Beta distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Student%27s_t-distribution">Student's t-distribution</a>
+// <a href="https://en.wikipedia.org/wiki/F-distribution">F-distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Poisson_distribution">Poisson distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Binomial_distribution">Binomial distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Negative_binomial_distribution">Negative binomial distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Geometric_distribution">Geometric distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Hypergeometric_distribution">Hypergeometric distribution</a>
+
+use std::fmt;
+
+use crate::beta_fn::log_beta;
+use crate::igamma_fn::{p_normal, q_normal, log_gamma_pdf, p_gamma_dist, q_gamma_dist};
+use crate::igamma_inv_fn::{p_normal_inv, q_normal_inv, p_gamma_dist_inv, q_gamma_dist_inv};
+use crate::ibeta_fn::{beta_pdf, log_beta_pdf, p_beta_dist, q_beta_dist, p_t, q_t, p_f, q_f};
+use crate::ibeta_inv_fn::{p_beta_inv, q_beta_inv, p_t_inv, q_t_inv, p_f_inv, q_f_inv};
+use crate::discrete_fn::{poisson_pmf, log_poisson_pmf, p_poisson, q_poisson};
+use crate::discrete_fn::{binomial_pmf, log_binomial_pmf, p_binomial, q_binomial};
+use crate::discrete_fn::{negbinomial_pmf, log_negbinomial_pmf, p_negbinomial, q_negbinomial};
+use crate::discrete_fn::{hypergeometric_pmf, p_hypergeometric, q_hypergeometric};
+use crate::discrete_fn::{log_hypergeometric_pmf, support_of_hypergeometric};
+
+/// The error returned by the constructors of the distributions for an invalid parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParameterError {
+    /// The name of the parameter
+    pub name: &'static str,
+    /// The given value
+    pub value: f64,
+    /// The condition which the parameter must satisfy
+    pub requirement: &'static str,
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid parameter {} = {}: it must be {}", self.name, self.value, self.requirement)
+    }
+}
+
+impl std::error::Error for ParameterError {}
+
+fn finite(name: &'static str, value: f64) -> Result<f64, ParameterError> {
+    if value.is_finite() { Ok(value) }else{ Err(ParameterError { name, value, requirement: "finite" }) }
+}
+
+fn positive(name: &'static str, value: f64) -> Result<f64, ParameterError> {
+    if value > 0. && value.is_finite() { Ok(value) }else{ Err(ParameterError { name, value, requirement: "positive and finite" }) }
+}
+
+/// A continuous probability distribution on the real line.
+///
+/// ```
+/// use comonjo_mathfn::{ContinuousDistribution, Normal, StudentT};
+///
+/// // the two-sided 95% interval of a distribution
+/// fn central_interval<D: ContinuousDistribution>(d: &D) -> (f64, f64) {
+///     (d.quantile(0.025), d.isf(0.025))
+/// }
+///
+/// let (lo, hi) = central_interval(&Normal::new(0., 1.).unwrap());
+/// assert!((hi - 1.959963984540054).abs() <= 1e-15 && lo == -hi);
+///
+/// let (_, hi) = central_interval(&StudentT::new(10.).unwrap());
+/// assert!((hi - 2.2281388519862744).abs() <= 1e-14);
+/// ```
+pub trait ContinuousDistribution {
+    /// Return a value of the probability density function.
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Return the logarithm of the probability density function.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// Return a value of the cumulative distribution function *P(X ≤ x)*.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Return a value of the survival function *P(X > x)*.
+    fn sf(&self, x: f64) -> f64;
+
+    /// Return a value of the quantile function, the inverse of the CDF.
+    fn quantile(&self, p: f64) -> f64;
+
+    /// Return a value of the inverse survival function, that is, *x* such that *P(X > x) = q*.
+    fn isf(&self, q: f64) -> f64;
+
+    /// Return the mean (NaN if undefined and ∞ if it diverges).
+    fn mean(&self) -> f64;
+
+    /// Return the variance (NaN if undefined and ∞ if it diverges).
+    fn variance(&self) -> f64;
+
+    /// Return the skewness (NaN if undefined and ∞ if it diverges).
+    fn skewness(&self) -> f64;
+
+    /// Return the excess kurtosis (NaN if undefined and ∞ if it diverges).
+    fn kurtosis(&self) -> f64;
+
+    /// Return the infimum and the supremum of the support.
+    fn support(&self) -> (f64, f64);
+}
+
+//***** normal distribution *****
+/// The normal distribution *N(μ, σ²)*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    sd: f64,
+}
+
+impl Normal {
+    /// Return the normal distribution with the mean *μ* and the standard deviation *σ > 0*.
+    pub fn new(mean: f64, sd: f64) -> Result<Self, ParameterError> {
+        Ok(Normal { mean: finite("mean", mean)?, sd: positive("sd", sd)? })
+    }
+}
+
+impl ContinuousDistribution for Normal {
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.sd;
+        -0.5 * z * z - self.sd.ln() - 0.5 * (2. * std::f64::consts::PI).ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 { p_normal((x - self.mean) / self.sd) }
+    fn sf(&self, x: f64) -> f64 { q_normal((x - self.mean) / self.sd) }
+    fn quantile(&self, p: f64) -> f64 { self.mean + self.sd * p_normal_inv(p) }
+    fn isf(&self, q: f64) -> f64 { self.mean + self.sd * q_normal_inv(q) }
+
+    fn mean(&self) -> f64 { self.mean }
+    fn variance(&self) -> f64 { self.sd * self.sd }
+    fn skewness(&self) -> f64 { 0. }
+    fn kurtosis(&self) -> f64 { 0. }
+    fn support(&self) -> (f64, f64) { (f64::NEG_INFINITY, f64::INFINITY) }
+}
+
+//***** chi square distribution *****
+/// The chi-square distribution *χ²(k)*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquared {
+    k: f64,
+}
+
+impl ChiSquared {
+    /// Return the chi-square distribution with *k > 0* degrees of freedom.
+    pub fn new(k: f64) -> Result<Self, ParameterError> {
+        Ok(ChiSquared { k: positive("k", k)? })
+    }
+}
+
+impl ContinuousDistribution for ChiSquared {
+    fn pdf(&self, x: f64) -> f64 { self.ln_pdf(x).exp() }
+    fn ln_pdf(&self, x: f64) -> f64 { log_gamma_pdf(x, 0.5 * self.k, 2.) }
+    fn cdf(&self, x: f64) -> f64 { p_gamma_dist(x, 0.5 * self.k, 2.) }
+    fn sf(&self, x: f64) -> f64 { q_gamma_dist(x, 0.5 * self.k, 2.) }
+    fn quantile(&self, p: f64) -> f64 { p_gamma_dist_inv(p, 0.5 * self.k, 2.) }
+    fn isf(&self, q: f64) -> f64 { q_gamma_dist_inv(q, 0.5 * self.k, 2.) }
+
+    fn mean(&self) -> f64 { self.k }
+    fn variance(&self) -> f64 { 2. * self.k }
+    fn skewness(&self) -> f64 { (8. / self.k).sqrt() }
+    fn kurtosis(&self) -> f64 { 12. / self.k }
+    fn support(&self) -> (f64, f64) { (0., f64::INFINITY) }
+}
+
+//***** gamma distribution *****
+/// The gamma distribution with the shape *k* and the scale *θ*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+}
+
+impl Gamma {
+    /// Return the gamma distribution with the shape *k > 0* and the scale *θ > 0*.
+    pub fn new(shape: f64, scale: f64) -> Result<Self, ParameterError> {
+        Ok(Gamma { shape: positive("shape", shape)?, scale: positive("scale", scale)? })
+    }
+
+    /// Return the gamma distribution with the shape *k > 0* and the rate *β = 1/θ > 0*.
+    pub fn with_rate(shape: f64, rate: f64) -> Result<Self, ParameterError> {
+        Gamma::new(shape, 1. / positive("rate", rate)?)
+    }
+}
+
+impl ContinuousDistribution for Gamma {
+    fn pdf(&self, x: f64) -> f64 { self.ln_pdf(x).exp() }
+    fn ln_pdf(&self, x: f64) -> f64 { log_gamma_pdf(x, self.shape, self.scale) }
+    fn cdf(&self, x: f64) -> f64 { p_gamma_dist(x, self.shape, self.scale) }
+    fn sf(&self, x: f64) -> f64 { q_gamma_dist(x, self.shape, self.scale) }
+    fn quantile(&self, p: f64) -> f64 { p_gamma_dist_inv(p, self.shape, self.scale) }
+    fn isf(&self, q: f64) -> f64 { q_gamma_dist_inv(q, self.shape, self.scale) }
+
+    fn mean(&self) -> f64 { self.shape * self.scale }
+    fn variance(&self) -> f64 { self.shape * self.scale * self.scale }
+    fn skewness(&self) -> f64 { 2. / self.shape.sqrt() }
+    fn kurtosis(&self) -> f64 { 6. / self.shape }
+    fn support(&self) -> (f64, f64) { (0., f64::INFINITY) }
+}
+
+//***** beta distribution *****
+/// The beta distribution *Beta(a, b)*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+    a: f64,
+    b: f64,
+}
+
+impl Beta {
+    /// Return the beta distribution with the shapes *a > 0* and *b > 0*.
+    pub fn new(a: f64, b: f64) -> Result<Self, ParameterError> {
+        Ok(Beta { a: positive("a", a)?, b: positive("b", b)? })
+    }
+}
+
+impl ContinuousDistribution for Beta {
+    fn pdf(&self, x: f64) -> f64 { beta_pdf(x, self.a, self.b) }
+    fn ln_pdf(&self, x: f64) -> f64 { log_beta_pdf(x, self.a, self.b) }
+    fn cdf(&self, x: f64) -> f64 { p_beta_dist(x, self.a, self.b) }
+    fn sf(&self, x: f64) -> f64 { q_beta_dist(x, self.a, self.b) }
+    fn quantile(&self, p: f64) -> f64 { p_beta_inv(p, self.a, self.b) }
+    fn isf(&self, q: f64) -> f64 { q_beta_inv(q, self.a, self.b) }
+
+    fn mean(&self) -> f64 { self.a / (self.a + self.b) }
+
+    fn variance(&self) -> f64 {
+        let (a, b) = (self.a, self.b);
+        a * b / ((a + b) * (a + b) * (a + b + 1.))
+    }
+
+    fn skewness(&self) -> f64 {
+        let (a, b) = (self.a, self.b);
+        2. * (b - a) * (a + b + 1.).sqrt() / ((a + b + 2.) * (a * b).sqrt())
+    }
+
+    fn kurtosis(&self) -> f64 {
+        let (a, b) = (self.a, self.b);
+        6. * ((a - b) * (a - b) * (a + b + 1.) - a * b * (a + b + 2.)) / (a * b * (a + b + 2.) * (a + b + 3.))
+    }
+
+    fn support(&self) -> (f64, f64) { (0., 1.) }
+}
+
+//***** Student's t distribution *****
+/// Student's t distribution with *ν* degrees of freedom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    nu: f64,
+}
+
+impl StudentT {
+    /// Return Student's t distribution with *ν > 0* degrees of freedom.
+    pub fn new(nu: f64) -> Result<Self, ParameterError> {
+        Ok(StudentT { nu: positive("nu", nu)? })
+    }
+}
+
+impl ContinuousDistribution for StudentT {
+    fn pdf(&self, x: f64) -> f64 { self.ln_pdf(x).exp() }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        // (1 + x²/ν)^{-(ν+1)/2}/(√ν Β(ν/2, 1/2)), where log(1 + x²/ν) = 2 log|x| - log ν + log(1 + ν/x²) for large |x|
+        let nu = self.nu;
+        let log_nu = nu.ln();
+        let log_sum = if x * x > nu { 2. * x.abs().ln() - log_nu + (nu / (x * x)).ln_1p() }else{ (x * x / nu).ln_1p() };
+        -0.5 * (nu + 1.) * log_sum - 0.5 * log_nu - log_beta(0.5 * nu, 0.5)
+    }
+
+    fn cdf(&self, x: f64) -> f64 { p_t(x, self.nu) }
+    fn sf(&self, x: f64) -> f64 { q_t(x, self.nu) }
+    fn quantile(&self, p: f64) -> f64 { p_t_inv(p, self.nu) }
+    fn isf(&self, q: f64) -> f64 { q_t_inv(q, self.nu) }
+
+    fn mean(&self) -> f64 {
+        if self.nu > 1. { 0. }else{ f64::NAN }
+    }
+
+    fn variance(&self) -> f64 {
+        let nu = self.nu;
+        if nu > 2. { nu / (nu - 2.) }else if nu > 1. { f64::INFINITY }else{ f64::NAN }
+    }
+
+    fn skewness(&self) -> f64 {
+        if self.nu > 3. { 0. }else{ f64::NAN }
+    }
+
+    fn kurtosis(&self) -> f64 {
+        let nu = self.nu;
+        if nu > 4. { 6. / (nu - 4.) }else if nu > 2. { f64::INFINITY }else{ f64::NAN }
+    }
+
+    fn support(&self) -> (f64, f64) { (f64::NEG_INFINITY, f64::INFINITY) }
+}
+
+//***** F distribution *****
+/// The F distribution with *d1* and *d2* degrees of freedom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct F {
+    d1: f64,
+    d2: f64,
+}
+
+impl F {
+    /// Return the F distribution with *d1 > 0* and *d2 > 0* degrees of freedom.
+    pub fn new(d1: f64, d2: f64) -> Result<Self, ParameterError> {
+        Ok(F { d1: positive("d1", d1)?, d2: positive("d2", d2)? })
+    }
+}
+
+impl ContinuousDistribution for F {
+    fn pdf(&self, x: f64) -> f64 { self.ln_pdf(x).exp() }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if x.is_nan() { return f64::NAN; }
+        if x < 0. || x.is_infinite() { return f64::NEG_INFINITY; }
+        if x == 0. {
+            return if d1 < 2. { f64::INFINITY }else if d1 > 2. { f64::NEG_INFINITY }else{ 0. };
+        }
+
+        // u^{d1/2}(1-u)^{d2/2}/(xΒ(d1/2, d2/2)), where u = r/(1+r) and 1 - u = 1/(1+r) (r = d1x/d2)
+        let r = d1 * x / d2;
+        let log_u = if r > 1. { -(1. / r).ln_1p() }else{ r.ln() - r.ln_1p() };
+        0.5 * d1 * log_u - 0.5 * d2 * r.ln_1p() - x.ln() - log_beta(0.5 * d1, 0.5 * d2)
+    }
+
+    fn cdf(&self, x: f64) -> f64 { p_f(x, self.d1, self.d2) }
+    fn sf(&self, x: f64) -> f64 { q_f(x, self.d1, self.d2) }
+    fn quantile(&self, p: f64) -> f64 { p_f_inv(p, self.d1, self.d2) }
+    fn isf(&self, q: f64) -> f64 { q_f_inv(q, self.d1, self.d2) }
+
+    fn mean(&self) -> f64 {
+        let d2 = self.d2;
+        if d2 > 2. { d2 / (d2 - 2.) }else{ f64::NAN }
+    }
+
+    fn variance(&self) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if d2 > 4. {
+            2. * d2 * d2 * (d1 + d2 - 2.) / (d1 * (d2 - 2.) * (d2 - 2.) * (d2 - 4.))
+        }else if d2 > 2. {
+            f64::INFINITY
+        }else{
+            f64::NAN
+        }
+    }
+
+    fn skewness(&self) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if d2 > 6. {
+            (2. * d1 + d2 - 2.) * (8. * (d2 - 4.)).sqrt() / ((d2 - 6.) * (d1 * (d1 + d2 - 2.)).sqrt())
+        }else if d2 > 4. {
+            f64::INFINITY
+        }else{
+            f64::NAN
+        }
+    }
+
+    fn kurtosis(&self) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if d2 > 8. {
+            12. * (d1 * (5. * d2 - 22.) * (d1 + d2 - 2.) + (d2 - 4.) * (d2 - 2.) * (d2 - 2.))
+                / (d1 * (d2 - 6.) * (d2 - 8.) * (d1 + d2 - 2.))
+        }else if d2 > 4. {
+            f64::INFINITY
+        }else{
+            f64::NAN
+        }
+    }
+
+    fn support(&self) -> (f64, f64) { (0., f64::INFINITY) }
+}
+
+fn count(name: &'static str, value: f64) -> Result<f64, ParameterError> {
+    if value >= 0. && value.fract() == 0. { Ok(value) }else{ Err(ParameterError { name, value, requirement: "a non-negative integer" }) }
+}
+
+fn probability(name: &'static str, value: f64) -> Result<f64, ParameterError> {
+    if (0. ..=1.).contains(&value) { Ok(value) }else{ Err(ParameterError { name, value, requirement: "in [0, 1]" }) }
+}
+
+fn positive_probability(name: &'static str, value: f64) -> Result<f64, ParameterError> {
+    if value > 0. && value <= 1. { Ok(value) }else{ Err(ParameterError { name, value, requirement: "in (0, 1]" }) }
+}
+
+/// A discrete probability distribution on the integers.
+///
+/// The arguments *k* are `f64` as the functions such as [`poisson_pmf`](crate::poisson_pmf),
+/// and the CDFs are those at *⌊k⌋*.
+///
+/// ```
+/// use comonjo_mathfn::{DiscreteDistribution, Binomial, Poisson};
+///
+/// // the smallest k such that P(X > k) ≤ 1%
+/// fn upper_limit<D: DiscreteDistribution>(d: &D) -> f64 {
+///     d.isf(0.01)
+/// }
+///
+/// assert_eq!(upper_limit(&Poisson::new(3.5).unwrap()), 8.);
+/// assert_eq!(upper_limit(&Binomial::new(20., 0.3).unwrap()), 11.);
+/// ```
+pub trait DiscreteDistribution {
+    /// Return a value of the probability mass function *P(X = k)*.
+    fn pmf(&self, k: f64) -> f64;
+
+    /// Return the logarithm of the probability mass function.
+    fn ln_pmf(&self, k: f64) -> f64 {
+        self.pmf(k).ln()
+    }
+
+    /// Return a value of the cumulative distribution function *P(X ≤ k)*.
+    fn cdf(&self, k: f64) -> f64;
+
+    /// Return a value of the survival function *P(X > k)*.
+    fn sf(&self, k: f64) -> f64;
+
+    /// Return a value of the quantile function, that is, the smallest *k* such that *P(X ≤ k) ≥ p*.
+    fn quantile(&self, p: f64) -> f64 {
+        if !(0. ..=1.).contains(&p) { return f64::NAN; }
+        if p == 1. { return self.support().1; }
+        find_discrete_quantile(self, p_normal_inv(p), |k| self.cdf(k), |c| c >= p)
+    }
+
+    /// Return a value of the inverse survival function, that is, the smallest *k* such that *P(X > k) ≤ q*.
+    fn isf(&self, q: f64) -> f64 {
+        if !(0. ..=1.).contains(&q) { return f64::NAN; }
+        if q == 0. { return self.support().1; }
+        find_discrete_quantile(self, q_normal_inv(q), |k| self.sf(k), |c| c <= q)
+    }
+
+    /// Return the mean.
+    fn mean(&self) -> f64;
+
+    /// Return the variance.
+    fn variance(&self) -> f64;
+
+    /// Return the skewness (NaN if the distribution is degenerate, that is, the variance is 0).
+    fn skewness(&self) -> f64;
+
+    /// Return the excess kurtosis (NaN if the distribution is degenerate).
+    fn kurtosis(&self) -> f64;
+
+    /// Return the minimum and the maximum (∞ if unbounded) of the support.
+    fn support(&self) -> (f64, f64);
+}
+
+/// Return the smallest integer *k* in the support such that `reached(value(k))` (NaN if `value` is NaN),
+/// searched from the normal approximation *μ + σz*.
+fn find_discrete_quantile<D, V, R>(d: &D, z: f64, value: V, reached: R) -> f64
+    where D: DiscreteDistribution + ?Sized,
+          V: Fn(f64) -> f64,
+          R: Fn(f64) -> bool
+{
+    let reached_at = |k: f64| {
+        let v = value(k);
+        if v.is_nan() { None }else{ Some(reached(v)) }
+    };
+    search_discrete_quantile(d, z, reached_at).unwrap_or(f64::NAN)
+}
+
+/// Return the smallest integer *k* in the support such that `reached(k)` is true, or `None` if `reached(k)` is `None`.
+fn search_discrete_quantile<D, R>(d: &D, z: f64, reached: R) -> Option<f64>
+    where D: DiscreteDistribution + ?Sized,
+          R: Fn(f64) -> Option<bool>
+{
+    let (lo, hi) = d.support();
+    if reached(lo)? { return Some(lo); }
+
+    let guess = (d.mean() + d.variance().sqrt() * z).floor();
+    let guess = if guess.is_finite() { guess.clamp(lo, hi) }else{ lo };
+
+    // bracket [a, b] such that !reached(a) and reached(b)
+    let (mut a, mut b);
+    let mut step = 1.;
+    if reached(guess)? {
+        b = guess;
+        loop {
+            a = (b - step).max(lo);
+            if !reached(a)? { break; }
+            b = a;
+            step *= 2.;
+        }
+    }else{
+        a = guess;
+        loop {
+            b = (a + step).min(hi);
+            if b.is_infinite() || reached(b)? { break; }
+            a = b;
+            step *= 2.;
+        }
+    }
+
+    while b - a > 1. {
+        let m = (0.5 * (a + b)).floor();
+        if reached(m)? { b = m; }else{ a = m; }
+    }
+    Some(b)
+}
+
+/// Return `value` (a higher moment) or NaN if the distribution is degenerate (the variance is 0).
+fn unless_degenerate<D: DiscreteDistribution + ?Sized>(d: &D, value: f64) -> f64 {
+    if d.variance() == 0. { f64::NAN }else{ value }
+}
+
+//***** Poisson distribution *****
+/// The Poisson distribution with the mean *λ*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Return the Poisson distribution with the mean *λ > 0*.
+    pub fn new(lambda: f64) -> Result<Self, ParameterError> {
+        Ok(Poisson { lambda: positive("lambda", lambda)? })
+    }
+}
+
+impl DiscreteDistribution for Poisson {
+    fn pmf(&self, k: f64) -> f64 { poisson_pmf(k, self.lambda) }
+    fn ln_pmf(&self, k: f64) -> f64 { log_poisson_pmf(k, self.lambda) }
+    fn cdf(&self, k: f64) -> f64 { p_poisson(k, self.lambda) }
+    fn sf(&self, k: f64) -> f64 { q_poisson(k, self.lambda) }
+
+    fn mean(&self) -> f64 { self.lambda }
+    fn variance(&self) -> f64 { self.lambda }
+    fn skewness(&self) -> f64 { 1. / self.lambda.sqrt() }
+    fn kurtosis(&self) -> f64 { 1. / self.lambda }
+    fn support(&self) -> (f64, f64) { (0., f64::INFINITY) }
+}
+
+//***** binomial distribution *****
+/// The binomial distribution with *n* trials and the success probability *p*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: f64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Return the binomial distribution with *n* trials (a non-negative integer) and the success probability *0 ≤ p ≤ 1*.
+    pub fn new(n: f64, p: f64) -> Result<Self, ParameterError> {
+        Ok(Binomial { n: count("n", n)?, p: probability("p", p)? })
+    }
+}
+
+impl DiscreteDistribution for Binomial {
+    fn pmf(&self, k: f64) -> f64 { binomial_pmf(k, self.n, self.p) }
+    fn ln_pmf(&self, k: f64) -> f64 { log_binomial_pmf(k, self.n, self.p) }
+    fn cdf(&self, k: f64) -> f64 { p_binomial(k, self.n, self.p) }
+    fn sf(&self, k: f64) -> f64 { q_binomial(k, self.n, self.p) }
+
+    fn mean(&self) -> f64 { self.n * self.p }
+    fn variance(&self) -> f64 { self.n * self.p * (1. - self.p) }
+    fn skewness(&self) -> f64 { unless_degenerate(self, (1. - 2. * self.p) / self.variance().sqrt()) }
+    fn kurtosis(&self) -> f64 { unless_degenerate(self, (1. - 6. * self.p * (1. - self.p)) / self.variance()) }
+    fn support(&self) -> (f64, f64) { (0., self.n) }
+}
+
+//***** negative binomial distribution *****
+/// The negative binomial distribution, the number of failures before the *r*-th success
+/// with the success probability *p*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+}
+
+impl NegativeBinomial {
+    /// Return the negative binomial distribution with *r > 0* and the success probability *0 < p ≤ 1*.
+    pub fn new(r: f64, p: f64) -> Result<Self, ParameterError> {
+        Ok(NegativeBinomial { r: positive("r", r)?, p: positive_probability("p", p)? })
+    }
+}
+
+impl DiscreteDistribution for NegativeBinomial {
+    fn pmf(&self, k: f64) -> f64 { negbinomial_pmf(k, self.r, self.p) }
+    fn ln_pmf(&self, k: f64) -> f64 { log_negbinomial_pmf(k, self.r, self.p) }
+    fn cdf(&self, k: f64) -> f64 { p_negbinomial(k, self.r, self.p) }
+    fn sf(&self, k: f64) -> f64 { q_negbinomial(k, self.r, self.p) }
+
+    fn mean(&self) -> f64 { self.r * (1. - self.p) / self.p }
+    fn variance(&self) -> f64 { self.r * (1. - self.p) / (self.p * self.p) }
+    fn skewness(&self) -> f64 { unless_degenerate(self, (2. - self.p) / (self.r * (1. - self.p)).sqrt()) }
+    fn kurtosis(&self) -> f64 { unless_degenerate(self, 6. / self.r + self.p * self.p / (self.r * (1. - self.p))) }
+    fn support(&self) -> (f64, f64) { (0., f64::INFINITY) }
+}
+
+//***** geometric distribution *****
+/// The geometric distribution, the number of failures before the first success
+/// with the success probability *p*, that is, the negative binomial distribution with *r = 1*.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometric {
+    p: f64,
+}
+
+impl Geometric {
+    /// Return the geometric distribution with the success probability *0 < p ≤ 1*.
+    pub fn new(p: f64) -> Result<Self, ParameterError> {
+        Ok(Geometric { p: positive_probability("p", p)? })
+    }
+}
+
+impl DiscreteDistribution for Geometric {
+    fn pmf(&self, k: f64) -> f64 { negbinomial_pmf(k, 1., self.p) }
+    fn ln_pmf(&self, k: f64) -> f64 { log_negbinomial_pmf(k, 1., self.p) }
+    fn cdf(&self, k: f64) -> f64 { p_negbinomial(k, 1., self.p) }
+    fn sf(&self, k: f64) -> f64 { q_negbinomial(k, 1., self.p) }
+
+    fn mean(&self) -> f64 { (1. - self.p) / self.p }
+    fn variance(&self) -> f64 { (1. - self.p) / (self.p * self.p) }
+    fn skewness(&self) -> f64 { unless_degenerate(self, (2. - self.p) / (1. - self.p).sqrt()) }
+    fn kurtosis(&self) -> f64 { unless_degenerate(self, 6. + self.p * self.p / (1. - self.p)) }
+    fn support(&self) -> (f64, f64) { (0., f64::INFINITY) }
+}
+
+//***** hypergeometric distribution *****
+/// The hypergeometric distribution, the number of successes in *n* draws without replacement
+/// from a population of *N* with *K* successes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hypergeometric {
+    population: f64,
+    successes: f64,
+    draws: f64,
+}
+
+impl Hypergeometric {
+    /// Return the hypergeometric distribution with the population *N*, the successes *K ≤ N* and the draws *n ≤ N*
+    /// (non-negative integers).
+    pub fn new(population: f64, successes: f64, draws: f64) -> Result<Self, ParameterError> {
+        let population = count("population", population)?;
+        let successes = count("successes", successes)?;
+        let draws = count("draws", draws)?;
+        if successes > population {
+            return Err(ParameterError { name: "successes", value: successes, requirement: "not greater than the population" });
+        }
+        if draws > population {
+            return Err(ParameterError { name: "draws", value: draws, requirement: "not greater than the population" });
+        }
+        Ok(Hypergeometric { population, successes, draws })
+    }
+}
+
+impl DiscreteDistribution for Hypergeometric {
+    fn pmf(&self, k: f64) -> f64 { hypergeometric_pmf(k, self.population, self.successes, self.draws) }
+
+    fn ln_pmf(&self, k: f64) -> f64 {
+        let (lo, hi) = self.support();
+        if k.is_nan() { return f64::NAN; }
+        if k.fract() != 0. || k < lo || k > hi { return f64::NEG_INFINITY; }
+        log_hypergeometric_pmf(k, self.population, self.successes, self.draws)
+    }
+
+    fn cdf(&self, k: f64) -> f64 { p_hypergeometric(k, self.population, self.successes, self.draws) }
+    fn sf(&self, k: f64) -> f64 { q_hypergeometric(k, self.population, self.successes, self.draws) }
+
+    fn mean(&self) -> f64 {
+        if self.population == 0. { 0. }else{ self.draws * self.successes / self.population }
+    }
+
+    fn variance(&self) -> f64 {
+        let (nn, kk, n) = (self.population, self.successes, self.draws);
+        if nn <= 1. { return 0.; }
+        n * (kk / nn) * ((nn - kk) / nn) * ((nn - n) / (nn - 1.))
+    }
+
+    fn skewness(&self) -> f64 {
+        let (nn, kk, n) = (self.population, self.successes, self.draws);
+        if nn <= 3. {
+            // X ~ Bernoulli(K/N) for n = 1 and K - X ~ Bernoulli(K/N) for n = N - 1 (the others are degenerate)
+            let p = kk / nn;
+            let sign = if n == 1. { 1. }else{ -1. };
+            return unless_degenerate(self, sign * (1. - 2. * p) / (p * (1. - p)).sqrt());
+        }
+        unless_degenerate(self, (nn - 2. * kk) * (nn - 1.).sqrt() * (nn - 2. * n) / ((n * kk * (nn - kk) * (nn - n)).sqrt() * (nn - 2.)))
+    }
+
+    fn kurtosis(&self) -> f64 {
+        let (nn, kk, n) = (self.population, self.successes, self.draws);
+        if nn <= 3. {
+            // the kurtosis of Bernoulli(K/N) as the skewness
+            let p = kk / nn;
+            return unless_degenerate(self, 1. / (p * (1. - p)) - 6.);
+        }
+        let u = n * kk * (nn - kk) * (nn - n);
+        let kurtosis = ((nn - 1.) * nn * nn * (nn * (nn + 1.) - 6. * kk * (nn - kk) - 6. * n * (nn - n)) + 6. * u * (5. * nn - 6.))
+            / (u * (nn - 2.) * (nn - 3.));
+        unless_degenerate(self, kurtosis)
+    }
+
+    fn support(&self) -> (f64, f64) { support_of_hypergeometric(self.population, self.successes, self.draws) }
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::igamma_fn::{p_chi2, q_chi2};
+#[cfg(test)]
+use crate::igamma_inv_fn::p_chi2_inv;
+
+/// Check the moments of the distribution by the Simpson rule on *[quantile(ε), isf(ε)]*.
+#[cfg(test)]
+fn assert_moments_by_integration<D: ContinuousDistribution>(d: &D, name: &str, eps: f64){
+    const N: usize = 200_000;
+    let (lo, hi) = (d.quantile(1e-13), d.isf(1e-13));
+    let h = (hi - lo) / N as f64;
+    let integrate = |f: &dyn Fn(f64) -> f64| {
+        (0..=N).map(|i| {
+            let x = lo + i as f64 * h;
+            let w = if i == 0 || i == N { 1. }else if i % 2 == 1 { 4. }else{ 2. };
+            w * f(x) * d.pdf(x)
+        }).sum::<f64>() * h / 3.
+    };
+
+    let mean = integrate(&|x| x);
+    let var = integrate(&|x| (x - mean).powi(2));
+    let skew = integrate(&|x| (x - mean).powi(3)) / var.powf(1.5);
+    let kurt = integrate(&|x| (x - mean).powi(4)) / (var * var) - 3.;
+
+    assert_approximately(integrate(&|_| 1.), 1., eps, &format!("the total probability of {}", name));
+    assert_approximately(d.mean(), mean, eps, &format!("the mean of {}", name));
+    assert_approximately(d.variance(), var, eps, &format!("the variance of {}", name));
+    assert_approximately(d.skewness(), skew, eps, &format!("the skewness of {}", name));
+    assert_approximately(d.kurtosis(), kurt, eps, &format!("the kurtosis of {}", name));
+}
+
+#[test]
+fn test_the_constructors_of_continuous_distributions(){
+    assert!(Normal::new(0., 1.).is_ok());
+    assert!(ChiSquared::new(0.5).is_ok());
+    assert!(Gamma::new(0.5, 2.).is_ok());
+    assert!(Gamma::with_rate(0.5, 2.).is_ok());
+    assert!(Beta::new(0.5, 2.).is_ok());
+    assert!(StudentT::new(0.5).is_ok());
+    assert!(F::new(0.5, 2.).is_ok());
+
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(Normal::new(x, 1.).is_err(), "Normal::new({}, 1) is an error", x);
+    }
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1., 0.] {
+        assert!(Normal::new(0., x).is_err(), "Normal::new(0, {}) is an error", x);
+        assert!(ChiSquared::new(x).is_err(), "ChiSquared::new({}) is an error", x);
+        assert!(Gamma::new(x, 1.).is_err(), "Gamma::new({}, 1) is an error", x);
+        assert!(Gamma::new(1., x).is_err(), "Gamma::new(1, {}) is an error", x);
+        assert!(Gamma::with_rate(1., x).is_err(), "Gamma::with_rate(1, {}) is an error", x);
+        assert!(Beta::new(x, 1.).is_err(), "Beta::new({}, 1) is an error", x);
+        assert!(Beta::new(1., x).is_err(), "Beta::new(1, {}) is an error", x);
+        assert!(StudentT::new(x).is_err(), "StudentT::new({}) is an error", x);
+        assert!(F::new(x, 1.).is_err(), "F::new({}, 1) is an error", x);
+        assert!(F::new(1., x).is_err(), "F::new(1, {}) is an error", x);
+    }
+
+    let err = Gamma::new(2., -1.).unwrap_err();
+    assert_eq!(err, ParameterError { name: "scale", value: -1., requirement: "positive and finite" });
+    assert_eq!(err.to_string(), "invalid parameter scale = -1: it must be positive and finite");
+}
+
+#[test]
+fn test_that_continuous_distributions_delegate_to_the_functions(){
+    let normal = Normal::new(1.5, 2.).unwrap();
+    should_the_same_mathfn("Normal(1.5, 2).cdf(x) = Φ((x-1.5)/2)", |x| normal.cdf(x), |x| p_normal((x - 1.5) / 2.)).assert();
+    should_the_same_mathfn("Normal(1.5, 2).sf(x) = 1 - Φ((x-1.5)/2)", |x| normal.sf(x), |x| q_normal((x - 1.5) / 2.)).assert();
+    should_the_same_mathfn(
+        "Normal(1.5, 2).pdf(x) = e^{-((x-1.5)/2)²/2}/(2√(2π))",
+            |x| normal.pdf(x),
+            |x| (-0.125 * (x - 1.5) * (x - 1.5)).exp() / (2. * (2. * std::f64::consts::PI).sqrt())).assert();
+
+    let chi2 = ChiSquared::new(3.).unwrap();
+    should_the_same_mathfn("ChiSquared(3).cdf(x) = p_chi2(x, 3)", |x| chi2.cdf(x), |x| p_chi2(x, 3.))
+        .var0(|v| v.range(0., 20.).end()).assert();
+    should_the_same_mathfn("ChiSquared(3).sf(x) = q_chi2(x, 3)", |x| chi2.sf(x), |x| q_chi2(x, 3.))
+        .var0(|v| v.range(0., 20.).end()).assert();
+    should_the_same_mathfn("ChiSquared(3).quantile(p) = p_chi2_inv(p, 3)", |p| chi2.quantile(p), |p| p_chi2_inv(p, 3.))
+        .var0(|v| v.range(0., 1.).end()).assert();
+
+    let gamma = Gamma::with_rate(2.5, 4.).unwrap();
+    should_the_same_mathfn("Gamma(2.5, rate 4).cdf(x) = P(2.5, 4x)", |x| gamma.cdf(x), |x| crate::igamma_fn::p_gamma(2.5, 4. * x))
+        .var0(|v| v.range(0., 5.).end()).assert();
+
+    let t = StudentT::new(4.5).unwrap();
+    should_the_same_mathfn(
+        "StudentT(ν).pdf(x) = Γ((ν+1)/2)/(√(νπ)Γ(ν/2))(1+x²/ν)^{-(ν+1)/2}",
+            |x| t.pdf(x),
+            |x| crate::gamma_fn::gamma(2.75) / ((4.5 * std::f64::consts::PI).sqrt() * crate::gamma_fn::gamma(2.25))
+                * (1. + x * x / 4.5).powf(-2.75)).assert();
+
+    // F(1, ν) is the distribution of T²: f_F(x) = f_T(√x)/√x
+    let f = F::new(1., 4.5).unwrap();
+    should_the_same_mathfn("F(1, ν).pdf(x) = f_T(√x)/√x", |x| f.pdf(x), |x| t.pdf(x.sqrt()) / x.sqrt())
+        .var0(|v| v.range(0.01, 20.).end()).assert();
+
+    // the values by mpmath, where the densities underflow or x² overflows
+    assert_reference_values("Beta(a, b).ln_pdf(x)", |(a, b, x)| Beta::new(a, b).unwrap().ln_pdf(x),
+        &[((2000., 5000., 0.9), -7530.638200430745)], EPS);
+    assert_reference_values("StudentT(ν).ln_pdf(x)", |(nu, x)| StudentT::new(nu).unwrap().ln_pdf(x), &[
+        ((1.,  1e200), -922.1787670834676),
+        ((3.5, 1e100), -1034.3340487594655),
+        ((5.,  2.),    -2.731979583761081)], EPS);
+    assert_reference_values("F(d1, d2).ln_pdf(x)", |(d1, d2, x)| F::new(d1, d2).unwrap().ln_pdf(x), &[
+        ((5.,   1e10, 1.),   -0.4939560410375315),
+        ((3.,   7.,   2.5),  -2.3561314608636885),
+        ((1e10, 5.,   1e-3), -2473.816188814183)], EPS);
+}
+
+#[test]
+fn test_the_quantiles_of_continuous_distributions(){
+    let ds: [(&str, Box<dyn ContinuousDistribution>); 7] = [
+        ("Normal(1.5, 2)",  Box::new(Normal::new(1.5, 2.).unwrap())),
+        ("ChiSquared(3)",   Box::new(ChiSquared::new(3.).unwrap())),
+        ("Gamma(2.5, 0.4)", Box::new(Gamma::new(2.5, 0.4).unwrap())),
+        ("Beta(2, 5)",      Box::new(Beta::new(2., 5.).unwrap())),
+        ("Beta(0.5, 0.5)",  Box::new(Beta::new(0.5, 0.5).unwrap())),
+        ("StudentT(4.5)",   Box::new(StudentT::new(4.5).unwrap())),
+        ("F(3, 10)",        Box::new(F::new(3., 10.).unwrap()))];
+
+    for (name, d) in ds.iter() {
+        should_the_same_mathfn(
+            &format!("{}.cdf(quantile(p)) = p", name),
+                |p| d.cdf(d.quantile(p)),
+                |p| p)
+            .var0(|v| v.range(0.001, 0.999).end()).assert();
+
+        should_the_same_mathfn(
+            &format!("{}.sf(isf(q)) = q", name),
+                |q| d.sf(d.isf(q)),
+                |q| q)
+            .var0(|v| v.range(0.001, 0.999).end()).assert();
+
+        should_the_same_mathfn(
+            &format!("{}.ln_pdf(x) = log {}.pdf(x)", name, name),
+                |p| d.ln_pdf(d.quantile(p)),
+                |p| d.pdf(d.quantile(p)).ln())
+            .var0(|v| v.range(0.001, 0.999).end()).assert();
+
+        let (lo, hi) = d.support();
+        assert_eq!(d.cdf(lo), 0., "{}.cdf({}) = 0", name, lo);
+        assert_eq!(d.sf(hi), 0., "{}.sf({}) = 0", name, hi);
+    }
+}
+
+#[test]
+fn test_the_moments_of_continuous_distributions(){
+    assert_moments_by_integration(&Normal::new(1.5, 2.).unwrap(), "Normal(1.5, 2)", 1e-8);
+    assert_moments_by_integration(&ChiSquared::new(5.).unwrap(), "ChiSquared(5)", 1e-8);
+    assert_moments_by_integration(&Gamma::new(3.5, 0.4).unwrap(), "Gamma(3.5, 0.4)", 1e-8);
+    assert_moments_by_integration(&Beta::new(2.5, 4.).unwrap(), "Beta(2.5, 4)", 1e-8);
+    assert_moments_by_integration(&StudentT::new(30.).unwrap(), "StudentT(30)", 1e-6);
+    assert_moments_by_integration(&F::new(5., 40.).unwrap(), "F(5, 40)", 1e-6);
+
+    // the undefined or divergent moments
+    let t = StudentT::new(3.).unwrap();
+    assert_eq!(t.variance(), 3.);
+    assert_eq!(t.kurtosis(), f64::INFINITY);
+    assert!(StudentT::new(1.).unwrap().mean().is_nan());
+    assert!(F::new(3., 2.).unwrap().mean().is_nan());
+
+    // the variance of F(d1, d2) diverges for 2 < d2 ≤ 4, the skewness for 4 < d2 ≤ 6 and the kurtosis for 4 < d2 ≤ 8
+    for d2 in [3., 4.] {
+        let f = F::new(3., d2).unwrap();
+        assert_eq!(f.variance(), f64::INFINITY, "F(3, {}).variance() = ∞", d2);
+        assert!(f.skewness().is_nan(), "F(3, {}).skewness() = NaN", d2);
+        assert!(f.kurtosis().is_nan(), "F(3, {}).kurtosis() = NaN", d2);
+    }
+    for d2 in [5., 6.] {
+        let f = F::new(3., d2).unwrap();
+        assert!(f.variance().is_finite(), "F(3, {}).variance() is finite", d2);
+        assert_eq!(f.skewness(), f64::INFINITY, "F(3, {}).skewness() = ∞", d2);
+        assert_eq!(f.kurtosis(), f64::INFINITY, "F(3, {}).kurtosis() = ∞", d2);
+    }
+    let f = F::new(3., 7.).unwrap();
+    assert!(f.skewness().is_finite() && f.kurtosis() == f64::INFINITY);
+    assert!(F::new(3., 2.).unwrap().variance().is_nan());
+}
+
+/// Check the moments of the distribution by the sum of the PMF on *[min, isf(ε)]*.
+#[cfg(test)]
+fn assert_moments_by_summation<D: DiscreteDistribution>(d: &D, name: &str, eps: f64){
+    let (lo, _) = d.support();
+    let hi = d.isf(1e-17);
+    let sum = |f: &dyn Fn(f64) -> f64| {
+        (lo as u64..=hi as u64).map(|k| f(k as f64) * d.pmf(k as f64)).sum::<f64>()
+    };
+
+    let mean = sum(&|k| k);
+    let var = sum(&|k| (k - mean).powi(2));
+    let skew = sum(&|k| (k - mean).powi(3)) / var.powf(1.5);
+    let kurt = sum(&|k| (k - mean).powi(4)) / (var * var) - 3.;
+
+    assert_approximately(sum(&|_| 1.), 1., eps, &format!("the total probability of {}", name));
+    assert_approximately(d.mean(), mean, eps, &format!("the mean of {}", name));
+    assert_approximately(d.variance(), var, eps, &format!("the variance of {}", name));
+    assert_approximately(d.skewness(), skew, eps, &format!("the skewness of {}", name));
+    assert_approximately(d.kurtosis(), kurt, eps, &format!("the kurtosis of {}", name));
+}
+
+#[test]
+fn test_the_constructors_of_discrete_distributions(){
+    assert!(Poisson::new(0.5).is_ok());
+    assert!(Binomial::new(0., 0.).is_ok());
+    assert!(Binomial::new(10., 1.).is_ok());
+    assert!(NegativeBinomial::new(0.5, 1.).is_ok());
+    assert!(Geometric::new(1.).is_ok());
+    assert!(Hypergeometric::new(10., 10., 0.).is_ok());
+
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1., 0.] {
+        assert!(Poisson::new(x).is_err(), "Poisson::new({}) is an error", x);
+        assert!(NegativeBinomial::new(x, 0.5).is_err(), "NegativeBinomial::new({}, 0.5) is an error", x);
+        assert!(NegativeBinomial::new(1., x).is_err(), "NegativeBinomial::new(1, {}) is an error", x);
+        assert!(Geometric::new(x).is_err(), "Geometric::new({}) is an error", x);
+    }
+    for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1., 2.5] {
+        assert!(Binomial::new(x, 0.5).is_err(), "Binomial::new({}, 0.5) is an error", x);
+        assert!(Hypergeometric::new(x, 1., 1.).is_err(), "Hypergeometric::new({}, 1, 1) is an error", x);
+        assert!(Hypergeometric::new(10., x, 1.).is_err(), "Hypergeometric::new(10, {}, 1) is an error", x);
+        assert!(Hypergeometric::new(10., 1., x).is_err(), "Hypergeometric::new(10, 1, {}) is an error", x);
+    }
+    for x in [f64::NAN, -0.5, 1.5] {
+        assert!(Binomial::new(1., x).is_err(), "Binomial::new(1, {}) is an error", x);
+        assert!(NegativeBinomial::new(1., x).is_err(), "NegativeBinomial::new(1, {}) is an error", x);
+    }
+
+    assert_eq!(Hypergeometric::new(10., 11., 1.).unwrap_err(),
+        ParameterError { name: "successes", value: 11., requirement: "not greater than the population" });
+    assert_eq!(Hypergeometric::new(10., 1., 11.).unwrap_err(),
+        ParameterError { name: "draws", value: 11., requirement: "not greater than the population" });
+}
+
+#[test]
+fn test_that_discrete_distributions_delegate_to_the_functions(){
+    let poisson = Poisson::new(3.5).unwrap();
+    let binomial = Binomial::new(20., 0.3).unwrap();
+    let negbinomial = NegativeBinomial::new(2.5, 0.4).unwrap();
+    let geometric = Geometric::new(0.3).unwrap();
+    let hypergeometric = Hypergeometric::new(50., 10., 20.).unwrap();
+    for k in (-1..=30).map(|k| k as f64) {
+        assert_eq!(poisson.pmf(k), poisson_pmf(k, 3.5));
+        assert_eq!(poisson.cdf(k), p_poisson(k, 3.5));
+        assert_eq!(binomial.pmf(k), binomial_pmf(k, 20., 0.3));
+        assert_eq!(binomial.sf(k), q_binomial(k, 20., 0.3));
+        assert_eq!(negbinomial.pmf(k), negbinomial_pmf(k, 2.5, 0.4));
+        assert_eq!(negbinomial.cdf(k), p_negbinomial(k, 2.5, 0.4));
+        assert_eq!(hypergeometric.pmf(k), hypergeometric_pmf(k, 50., 10., 20.));
+        assert_eq!(hypergeometric.sf(k), q_hypergeometric(k, 50., 10., 20.));
+        if k >= 0. {
+            assert_approximately(geometric.pmf(k), 0.3 * 0.7_f64.powf(k), EPS, &format!("Geometric(0.3).pmf({})", k));
+        }
+    }
+
+    // ln_pmf doesn't underflow for the large population
+    let hypergeometric = Hypergeometric::new(1e6, 1e4, 1e5).unwrap();
+    assert_approximately(hypergeometric.ln_pmf(1000.), 0.013363943066541633_f64.ln(), EPS, "Hypergeometric(1e6, 1e4, 1e5).ln_pmf(1000)");
+    assert!(hypergeometric.pmf(10.) == 0. && hypergeometric.ln_pmf(10.).is_finite());
+    assert_eq!(hypergeometric.ln_pmf(10001.), f64::NEG_INFINITY);
+
+    for k in [-1., 0.5, 0., 3., 1000.] {
+        assert_eq!(poisson.ln_pmf(k), log_poisson_pmf(k, 3.5));
+        assert_eq!(binomial.ln_pmf(k), log_binomial_pmf(k, 20., 0.3));
+        assert_eq!(negbinomial.ln_pmf(k), log_negbinomial_pmf(k, 2.5, 0.4));
+        assert_eq!(geometric.ln_pmf(k), log_negbinomial_pmf(k, 1., 0.3));
+    }
+    assert!(poisson.pmf(1000.) == 0. && poisson.ln_pmf(1000.).is_finite());
+    assert!(geometric.pmf(1e4) == 0. && geometric.ln_pmf(1e4).is_finite());
+
+    // the empty population
+    let hypergeometric = Hypergeometric::new(0., 0., 0.).unwrap();
+    assert_eq!((hypergeometric.pmf(0.), hypergeometric.cdf(0.), hypergeometric.sf(0.)), (1., 1., 0.));
+    assert_eq!((hypergeometric.mean(), hypergeometric.variance()), (0., 0.));
+}
+
+#[test]
+fn test_the_quantiles_of_discrete_distributions(){
+    let ds: [(&str, Box<dyn DiscreteDistribution>); 8] = [
+        ("Poisson(3.5)",                    Box::new(Poisson::new(3.5).unwrap())),
+        ("Poisson(1000)",                   Box::new(Poisson::new(1000.).unwrap())),
+        ("Binomial(20, 0.3)",               Box::new(Binomial::new(20., 0.3).unwrap())),
+        ("Binomial(1e6, 0.01)",             Box::new(Binomial::new(1e6, 0.01).unwrap())),
+        ("NegativeBinomial(2.5, 0.4)",      Box::new(NegativeBinomial::new(2.5, 0.4).unwrap())),
+        ("Geometric(0.05)",                 Box::new(Geometric::new(0.05).unwrap())),
+        ("Hypergeometric(50, 10, 20)",      Box::new(Hypergeometric::new(50., 10., 20.).unwrap())),
+        ("Hypergeometric(1e6, 1e4, 1e5)",   Box::new(Hypergeometric::new(1e6, 1e4, 1e5).unwrap()))];
+
+    for (name, d) in ds.iter() {
+        let (lo, hi) = d.support();
+        for p in [0., 1e-10, 0.001, 0.025, 0.3, 0.5, 0.7, 0.975, 0.999, 1. - 1e-10] {
+            let k = d.quantile(p);
+            assert!(d.cdf(k) >= p && (k == lo || d.cdf(k - 1.) < p), "{}.quantile({}) = {}", name, p, k);
+            let k = d.isf(1. - p);
+            assert!(d.sf(k) <= 1. - p && (k == lo || d.sf(k - 1.) > 1. - p), "{}.isf({}) = {}", name, 1. - p, k);
+        }
+        assert_eq!(d.quantile(1.), hi, "{}.quantile(1) = {}", name, hi);
+        assert_eq!(d.isf(0.), hi, "{}.isf(0) = {}", name, hi);
+        assert_eq!(d.isf(1.), lo, "{}.isf(1) = {}", name, lo);
+        assert!(d.quantile(1.5).is_nan() && d.isf(f64::NAN).is_nan(), "{}.quantile is NaN out of [0, 1]", name);
+    }
+
+    // the large parameters, where the search starts far from the lower end of the support
+    let ds: [(&str, Box<dyn DiscreteDistribution>); 3] = [
+        ("Poisson(1e10)",                   Box::new(Poisson::new(1e10).unwrap())),
+        ("Binomial(1e9, 0.5)",              Box::new(Binomial::new(1e9, 0.5).unwrap())),
+        ("NegativeBinomial(1e12, 0.5)",     Box::new(NegativeBinomial::new(1e12, 0.5).unwrap()))];
+
+    for (name, d) in ds.iter() {
+        for p in [0.025, 0.5, 0.975] {
+            let k = d.quantile(p);
+            assert!(d.cdf(k) >= p && d.cdf(k - 1.) < p, "{}.quantile({}) = {}", name, p, k);
+        }
+    }
+
+    // a NaN of the CDF is not a value below p
+    struct NanAbove(Poisson);
+    impl DiscreteDistribution for NanAbove {
+        fn pmf(&self, k: f64) -> f64 { self.0.pmf(k) }
+        fn cdf(&self, k: f64) -> f64 { if k > 5. { f64::NAN }else{ self.0.cdf(k) } }
+        fn sf(&self, k: f64) -> f64 { if k > 5. { f64::NAN }else{ self.0.sf(k) } }
+        fn mean(&self) -> f64 { self.0.mean() }
+        fn variance(&self) -> f64 { self.0.variance() }
+        fn skewness(&self) -> f64 { self.0.skewness() }
+        fn kurtosis(&self) -> f64 { self.0.kurtosis() }
+        fn support(&self) -> (f64, f64) { self.0.support() }
+    }
+    let d = NanAbove(Poisson::new(3.5).unwrap());
+    assert!(d.quantile(0.999).is_nan() && d.isf(0.001).is_nan(), "the quantile is NaN if the CDF is NaN");
+    assert_eq!(d.quantile(0.5), Poisson::new(3.5).unwrap().quantile(0.5));
+}
+
+#[test]
+fn test_the_moments_of_discrete_distributions(){
+    // the degenerate distributions
+    let ds: [(&str, Box<dyn DiscreteDistribution>); 6] = [
+        ("Binomial(10, 0)",             Box::new(Binomial::new(10., 0.).unwrap())),
+        ("Binomial(10, 1)",             Box::new(Binomial::new(10., 1.).unwrap())),
+        ("NegativeBinomial(2.5, 1)",    Box::new(NegativeBinomial::new(2.5, 1.).unwrap())),
+        ("Geometric(1)",                Box::new(Geometric::new(1.).unwrap())),
+        ("Hypergeometric(10, 10, 3)",   Box::new(Hypergeometric::new(10., 10., 3.).unwrap())),
+        ("Hypergeometric(1, 1, 1)",     Box::new(Hypergeometric::new(1., 1., 1.).unwrap()))];
+    for (name, d) in ds.iter() {
+        assert_eq!(d.variance(), 0., "{}.variance() = 0", name);
+        assert!(d.skewness().is_nan() && d.kurtosis().is_nan(), "{} has no skewness and kurtosis", name);
+    }
+
+    assert_moments_by_summation(&Poisson::new(3.5).unwrap(), "Poisson(3.5)", 1e-10);
+    assert_moments_by_summation(&Binomial::new(20., 0.3).unwrap(), "Binomial(20, 0.3)", 1e-10);
+    assert_moments_by_summation(&NegativeBinomial::new(2.5, 0.4).unwrap(), "NegativeBinomial(2.5, 0.4)", 1e-10);
+    assert_moments_by_summation(&Geometric::new(0.3).unwrap(), "Geometric(0.3)", 1e-10);
+    assert_moments_by_summation(&Hypergeometric::new(50., 10., 20.).unwrap(), "Hypergeometric(50, 10, 20)", 1e-10);
+    assert_moments_by_summation(&Hypergeometric::new(1e6, 1e4, 1e5).unwrap(), "Hypergeometric(1e6, 1e4, 1e5)", 1e-8);
+
+    // N ≤ 3, where the general forms divide by N - 2 and N - 3
+    let d = Hypergeometric::new(2., 1., 1.).unwrap();
+    assert!(d.skewness() == 0. && d.kurtosis() == -2., "Hypergeometric(2, 1, 1) is Bernoulli(1/2)");
+    assert_approximately(Hypergeometric::new(3., 1., 1.).unwrap().kurtosis(), -1.5, EPS, "the kurtosis of Hypergeometric(3, 1, 1)");
+    assert_moments_by_summation(&Hypergeometric::new(3., 1., 1.).unwrap(), "Hypergeometric(3, 1, 1)", 1e-10);
+    assert_moments_by_summation(&Hypergeometric::new(3., 1., 2.).unwrap(), "Hypergeometric(3, 1, 2)", 1e-10);
+    assert_moments_by_summation(&Hypergeometric::new(3., 2., 2.).unwrap(), "Hypergeometric(3, 2, 2)", 1e-10);
+}
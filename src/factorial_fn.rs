@@ -0,0 +1,312 @@
+// The tests refer to
+// <a href="https://en.m.wikipedia.org/wiki/Factorial">Factorial</a>
+// <a href="https://en.m.wikipedia.org/wiki/Double_factorial">Double factorial</a>
+
+use crate::gamma_fn::{log_gamma, gamma};
+
+const SQRT_PI: f64 = 1.7724538509055159_f64;
+
+/// The largest *n* such that *n!* does not overflow as f64.
+const MAX_FACTORIAL: u32 = 170;
+
+/// *n!* (n = 0, 1, ..., 170) rounded to the nearest f64.
+const FACTORIALS: [f64; MAX_FACTORIAL as usize + 1] = [
+    1.0,
+    1.0,
+    2.0,
+    6.0,
+    24.0,
+    120.0,
+    720.0,
+    5040.0,
+    40320.0,
+    362880.0,
+    3628800.0,
+    39916800.0,
+    479001600.0,
+    6227020800.0,
+    87178291200.0,
+    1307674368000.0,
+    20922789888000.0,
+    355687428096000.0,
+    6402373705728000.0,
+    1.21645100408832e17,
+    2.43290200817664e18,
+    5.109094217170944e19,
+    1.1240007277776077e21,
+    2.585201673888498e22,
+    6.204484017332394e23,
+    1.5511210043330986e25,
+    4.0329146112660565e26,
+    1.0888869450418352e28,
+    3.0488834461171387e29,
+    8.841761993739702e30,
+    2.6525285981219107e32,
+    8.222838654177922e33,
+    2.631308369336935e35,
+    8.683317618811886e36,
+    2.9523279903960416e38,
+    1.0333147966386145e40,
+    3.7199332678990125e41,
+    1.3763753091226346e43,
+    5.230226174666011e44,
+    2.0397882081197444e46,
+    8.159152832478977e47,
+    3.345252661316381e49,
+    1.40500611775288e51,
+    6.041526306337383e52,
+    2.658271574788449e54,
+    1.1962222086548019e56,
+    5.502622159812089e57,
+    2.5862324151116818e59,
+    1.2413915592536073e61,
+    6.082818640342675e62,
+    3.0414093201713376e64,
+    1.5511187532873822e66,
+    8.065817517094388e67,
+    4.2748832840600255e69,
+    2.308436973392414e71,
+    1.2696403353658276e73,
+    7.109985878048635e74,
+    4.0526919504877214e76,
+    2.3505613312828785e78,
+    1.3868311854568984e80,
+    8.32098711274139e81,
+    5.075802138772248e83,
+    3.146997326038794e85,
+    1.98260831540444e87,
+    1.2688693218588417e89,
+    8.247650592082472e90,
+    5.443449390774431e92,
+    3.647111091818868e94,
+    2.4800355424368305e96,
+    1.711224524281413e98,
+    1.1978571669969892e100,
+    8.504785885678623e101,
+    6.1234458376886085e103,
+    4.4701154615126844e105,
+    3.307885441519386e107,
+    2.48091408113954e109,
+    1.8854947016660504e111,
+    1.4518309202828587e113,
+    1.1324281178206297e115,
+    8.946182130782976e116,
+    7.156945704626381e118,
+    5.797126020747368e120,
+    4.753643337012842e122,
+    3.945523969720659e124,
+    3.314240134565353e126,
+    2.81710411438055e128,
+    2.4227095383672734e130,
+    2.107757298379528e132,
+    1.8548264225739844e134,
+    1.650795516090846e136,
+    1.4857159644817615e138,
+    1.352001527678403e140,
+    1.2438414054641308e142,
+    1.1567725070816416e144,
+    1.087366156656743e146,
+    1.032997848823906e148,
+    9.916779348709496e149,
+    9.619275968248212e151,
+    9.426890448883248e153,
+    9.332621544394415e155,
+    9.332621544394415e157,
+    9.42594775983836e159,
+    9.614466715035127e161,
+    9.90290071648618e163,
+    1.0299016745145628e166,
+    1.081396758240291e168,
+    1.1462805637347084e170,
+    1.226520203196138e172,
+    1.324641819451829e174,
+    1.4438595832024937e176,
+    1.588245541522743e178,
+    1.7629525510902446e180,
+    1.974506857221074e182,
+    2.2311927486598138e184,
+    2.5435597334721877e186,
+    2.925093693493016e188,
+    3.393108684451898e190,
+    3.969937160808721e192,
+    4.684525849754291e194,
+    5.574585761207606e196,
+    6.689502913449127e198,
+    8.094298525273444e200,
+    9.875044200833601e202,
+    1.214630436702533e205,
+    1.506141741511141e207,
+    1.882677176888926e209,
+    2.372173242880047e211,
+    3.0126600184576594e213,
+    3.856204823625804e215,
+    4.974504222477287e217,
+    6.466855489220474e219,
+    8.47158069087882e221,
+    1.1182486511960043e224,
+    1.4872707060906857e226,
+    1.9929427461615188e228,
+    2.6904727073180504e230,
+    3.659042881952549e232,
+    5.012888748274992e234,
+    6.917786472619489e236,
+    9.615723196941089e238,
+    1.3462012475717526e241,
+    1.898143759076171e243,
+    2.695364137888163e245,
+    3.854370717180073e247,
+    5.5502938327393044e249,
+    8.047926057471992e251,
+    1.1749972043909107e254,
+    1.727245890454639e256,
+    2.5563239178728654e258,
+    3.80892263763057e260,
+    5.713383956445855e262,
+    8.62720977423324e264,
+    1.3113358856834524e267,
+    2.0063439050956823e269,
+    3.0897696138473508e271,
+    4.789142901463394e273,
+    7.471062926282894e275,
+    1.1729568794264145e278,
+    1.853271869493735e280,
+    2.9467022724950384e282,
+    4.7147236359920616e284,
+    7.590705053947219e286,
+    1.2296942187394494e289,
+    2.0044015765453026e291,
+    3.287218585534296e293,
+    5.423910666131589e295,
+    9.003691705778438e297,
+    1.503616514864999e300,
+    2.5260757449731984e302,
+    4.269068009004705e304,
+    7.257415615307999e306,
+];
+
+/// Return the factorial *n!* rounded to the nearest f64 (∞ if *n > 170*).
+/// 
+/// #### The value at the special points ####
+/// 
+/// ```
+/// use comonjo_mathfn::factorial;
+/// 
+/// assert_eq!(factorial(0), 1.);
+/// assert_eq!(factorial(5), 120.);
+/// assert_eq!(factorial(20), 2432902008176640000.);
+/// assert_eq!(factorial(171), f64::INFINITY);
+/// ```
+pub fn factorial(n: u32) -> f64 {
+    if n <= MAX_FACTORIAL { FACTORIALS[n as usize] }else{ f64::INFINITY }
+}
+
+/// Return the factorial *n!* as an integer, or `None` if it overflows u128 (*n > 34*).
+/// 
+/// ```
+/// use comonjo_mathfn::factorial_u128;
+/// 
+/// assert_eq!(factorial_u128(5), Some(120));
+/// assert_eq!(factorial_u128(34), Some(295232799039604140847618609643520000000));
+/// assert_eq!(factorial_u128(35), None);
+/// ```
+pub fn factorial_u128(n: u32) -> Option<u128> {
+    (2..=n as u128).try_fold(1_u128, |acc, k| acc.checked_mul(k))
+}
+
+/// Return the double factorial *n!! = n(n-2)(n-4)...* (∞ if it overflows f64).
+/// 
+/// ```
+/// use comonjo_mathfn::double_factorial;
+/// 
+/// assert_eq!(double_factorial(0), 1.);
+/// assert_eq!(double_factorial(7), 105.);
+/// assert_eq!(double_factorial(8), 384.);
+/// ```
+pub fn double_factorial(n: u32) -> f64 {
+    let k = n / 2;
+    if n % 2 == 1 {
+        if n <= MAX_FACTORIAL {
+            // (2k+1)!! = (2k+1)!/(2^k k!)
+            factorial(n) / (factorial(k) * 2_f64.powi(k as i32))
+        }else{
+            // (2k+1)!! = 2^{k+1}Γ(k+3/2)/√π
+            gamma(k as f64 + 1.5) / SQRT_PI * 2_f64.powi(k as i32 + 1)
+        }
+    }else{
+        // (2k)!! = 2^k k!
+        factorial(k) * 2_f64.powi(k as i32)
+    }
+}
+
+/// Return the double factorial *n!!* as an integer, or `None` if it overflows u128.
+pub fn double_factorial_u128(n: u32) -> Option<u128> {
+    (1..=n as u128).rev().step_by(2).try_fold(1_u128, |acc, k| acc.checked_mul(k))
+}
+
+/// Return the logarithm of the factorial *log n!*.
+/// The table of *n!* is used for *n ≤ 170* and the Stirling series otherwise.
+pub fn log_factorial(n: u32) -> f64 {
+    if n <= MAX_FACTORIAL {
+        FACTORIALS[n as usize].ln()
+    }else{
+        log_gamma(n as f64 + 1.)
+    }
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+
+#[test]
+fn test_that_the_factorial_is_the_exact_value_rounded(){
+    for n in 0..=34 {
+        assert_eq!(factorial(n), factorial_u128(n).unwrap() as f64, "n! at n = {}", n);
+    }
+
+    let mut exp = 1_f64;
+    for n in 1..=22 {  // products are exact up to 22!
+        exp *= n as f64;
+        assert_eq!(factorial(n), exp, "n! = n(n-1)! at n = {}", n);
+    }
+}
+
+#[test]
+fn test_the_values_of_factorials_out_of_the_range(){
+    assert_eq!(factorial(171), f64::INFINITY, "171! = ∞ (overflow)");
+    assert_eq!(factorial_u128(35), None, "35! overflows u128");
+    assert_eq!(double_factorial_u128(57), None, "57!! overflows u128");
+    assert!(double_factorial_u128(56).is_some(), "56!! doesn't overflow u128");
+    assert_eq!(double_factorial(400), f64::INFINITY, "400!! = ∞ (overflow)");
+}
+
+#[test]
+fn test_the_factorial_function_properties(){
+    should_the_same_mathfn(
+        "n! = Γ(n+1)",
+            |n| factorial(n as u32),
+            |n| gamma(n + 1.))
+        .var0(|v| v.name("n").range(0., 170.).is_integer(true).end()).assert();
+
+    should_the_same_mathfn(
+        "log n! = log Γ(n+1)",
+            |n| log_factorial(n as u32),
+            |n| log_gamma(n + 1.))
+        .var0(|v| v.name("n").range(0., 300.).is_integer(true).end()).assert();
+
+    should_the_same_mathfn(
+        "n!! = n(n-2)!!",
+            |n| double_factorial(n as u32),
+            |n| n * double_factorial(n as u32 - 2))
+        .var0(|v| v.name("n").range(2., 300.).is_integer(true).end()).assert();
+
+    should_the_same_mathfn(
+        "n!! = n!/(n-1)!!",
+            |n| double_factorial(n as u32),
+            |n| factorial(n as u32) / double_factorial(n as u32 - 1))
+        .var0(|v| v.name("n").range(1., 170.).is_integer(true).end()).assert();
+
+    should_the_same_mathfn(
+        "n!! (u128) = n!! (f64)",
+            |n| double_factorial_u128(n as u32).unwrap() as f64,
+            |n| double_factorial(n as u32))
+        .var0(|v| v.name("n").range(0., 56.).is_integer(true).end()).assert();
+}
@@ -3,7 +3,7 @@ use crate::integer_util::*;
 const PI: f64 = std::f64::consts::PI;
 const LOG_2PI: f64 = 1.8378770664093453_f64;
 
-const N: f64 = 8.;
+pub(crate) const N: f64 = 8.;
 
 const B2 : f64 = 1. / 6.;
 const B4 : f64 = -1. / 30.;
@@ -14,8 +14,10 @@ const B12: f64 = -691. / 2730.;
 const B14: f64 = 7. / 6.;
 const B16: f64 = -3617. / 510.;
 
+const BERNOULLI: [f64; 8] = [B2, B4, B6, B8, B10, B12, B14, B16];
+
 /// Return the logarithm of the gamma function *log Γ(x)*.
-/// (The argument *x* must be positive.)
+/// (The argument *x* must be positive. Use [`log_gamma_sign`] for negative arguments.)
 ///
 /// Ref: 『改訂新版 Cによる標準アルゴリズム事典』ガンマ関数 (gamma function) gamma.c
 pub fn log_gamma(mut x: f64) -> f64 {
@@ -27,12 +29,17 @@ pub fn log_gamma(mut x: f64) -> f64 {
         x += 1.;
     }
 
+    stirling_series(x) + 0.5 * LOG_2PI - v.ln() - x + (x - 0.5) * x.ln()
+}
+
+/// Return the Stirling series *Σ B₂ₖ/(2k(2k-1)x²ᵏ⁻¹)* (*k = 1, ..., 8*),
+/// which is accurate enough for *x ≥ N*.
+pub(crate) fn stirling_series(x: f64) -> f64 {
     let w = 1. / (x * x);
     ((((((((B16 / (16. * 15.))  * w + (B14 / (14. * 13.))) * w
          + (B12 / (12. * 11.))) * w + (B10 / (10. *  9.))) * w
          + (B8  / ( 8. *  7.))) * w + (B6  / ( 6. *  5.))) * w
          + (B4  / ( 4. *  3.))) * w + (B2  / ( 2. *  1.))) / x
-         + 0.5 * LOG_2PI - v.ln() - x + (x - 0.5) * x.ln()
 }
 
 #[test]
@@ -58,8 +65,104 @@ fn test_the_values_of_log_gamma_at_non_finite_args(){
     assert!(log_gamma(f64::NAN).is_nan(),               "logΓ(NaN) = NaN");
 }
 
+/// Return the logarithm of the absolute value of the gamma function and its sign, *(log |Γ(x)|, sgn Γ(x))*.
+/// This corresponds to `lgamma_r` of C.
+/// 
+/// The reflection formula *Γ(x)Γ(1-x) = π/sin(πx)* is used for negative *x*.
+/// The sign is NaN at the poles (*x = 0, -1, -2, ...*).
+/// 
+/// ```
+/// use comonjo_mathfn::log_gamma_sign;
+/// 
+/// let (log_abs, sign) = log_gamma_sign(-0.5);  // Γ(-1/2) = -2√π
+/// assert!((log_abs - (2. * std::f64::consts::PI.sqrt()).ln()).abs() <= 1e-14);
+/// assert_eq!(sign, -1.);
+/// 
+/// let (log_abs, sign) = log_gamma_sign(-1.);
+/// assert!(log_abs.is_infinite() && sign.is_nan());
+/// ```
+pub fn log_gamma_sign(x: f64) -> (f64, f64) {
+    if x.is_nan() || x == f64::NEG_INFINITY { return (f64::NAN, f64::NAN); }
+    if x > 0. { return (log_gamma(x), 1.); }
+    if x.fract() == 0. { return (f64::INFINITY, f64::NAN); }
+
+    let sin = sin_pi(x);
+    (PI.ln() - sin.abs().ln() - log_gamma(1. - x), sin.signum())
+}
+
+/// Return *sin(πx)* with the argument reduced to *[-1/2, 1/2]*,
+/// so that the zeros at integers are kept accurate.
+fn sin_pi(x: f64) -> f64 {
+    let n = x.round();
+    let sin = (PI * (x - n)).sin();
+    if n % 2. == 0. { sin }else{ -sin }
+}
+
+#[test]
+fn test_the_values_of_log_gamma_sign_at_the_specific_points(){
+    let log_sqrt_pi = 0.5 * PI.ln();
+
+    let (log_abs, sign) = log_gamma_sign(-0.5);
+    assert_approximately(log_abs, log_sqrt_pi + 2_f64.ln(), EPS, "log|Γ(-1/2)| = log(2√π)");
+    assert_eq!(sign, -1., "sgn Γ(-1/2) = -1");
+
+    let (log_abs, sign) = log_gamma_sign(-1.5);
+    assert_approximately(log_abs, log_sqrt_pi + (4. / 3_f64).ln(), EPS, "log|Γ(-3/2)| = log(4√π/3)");
+    assert_eq!(sign, 1., "sgn Γ(-3/2) = 1");
+
+    let (log_abs, sign) = log_gamma_sign(-2.5);
+    assert_approximately(log_abs, log_sqrt_pi + (8. / 15_f64).ln(), EPS, "log|Γ(-5/2)| = log(8√π/15)");
+    assert_eq!(sign, -1., "sgn Γ(-5/2) = -1");
+
+    // Γ(x) ~ 1/x near the pole 0
+    let (log_abs, sign) = log_gamma_sign(-1e-20);
+    assert_approximately(log_abs, 20. * std::f64::consts::LN_10, EPS, "log|Γ(-1e-20)| = log 1e20");
+    assert_eq!(sign, -1., "sgn Γ(-1e-20) = -1");
+
+    for n in 0..=5 {
+        let (log_abs, sign) = log_gamma_sign(-n as f64);
+        assert!(log_abs.is_infinite() && sign.is_nan(), "(log|Γ(-n)|, sgn Γ(-n)) = (∞, NaN) at -n = {}", -n);
+    }
+}
+
+#[test]
+fn test_the_values_of_log_gamma_sign_at_non_finite_args(){
+    assert_eq!(log_gamma_sign(f64::INFINITY), (f64::INFINITY, 1.), "(log|Γ(∞)|, sgn Γ(∞)) = (∞, 1)");
+    assert!(log_gamma_sign(f64::NEG_INFINITY).0.is_nan(), "log|Γ(-∞)| = NaN");
+    assert!(log_gamma_sign(f64::NAN).0.is_nan(),          "log|Γ(NaN)| = NaN");
+}
+
+#[test]
+fn test_the_log_gamma_sign_function_properties(){
+    const DELTA: f64 = 1e-3;
+
+    should_the_same_mathfn(
+        "sgn Γ(x) exp(log|Γ(x)|) = Γ(x)",
+            |x| { let (log_abs, sign) = log_gamma_sign(x); sign * log_abs.exp() },
+            gamma)
+        .filter(|x| !is_close_to_a_non_positive_integer(x, DELTA))
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn(
+        "log|Γ(x + 1)| = log|Γ(x)| + log|x|",
+            |x| log_gamma_sign(x + 1.).0,
+            |x| log_gamma_sign(x).0 + x.abs().ln())
+        .filter(|x| !is_close_to_a_non_positive_integer(x, DELTA))
+        .var0(|v| v.range(-200., 5.).end()).assert();
+
+    should_the_same_mathfn(
+        "sgn Γ(x + 1) = sgn x sgn Γ(x)",
+            |x| log_gamma_sign(x + 1.).1,
+            |x| x.signum() * log_gamma_sign(x).1)
+        .filter(|x| !is_close_to_a_non_positive_integer(x, DELTA))
+        .var0(|v| v.range(-200., 5.).end()).assert();
+}
+
 /// Return a value of the gamma function *Γ(x)*.
 /// 
+/// The value is not computed through *exp(log Γ(x))*, and the error is at most 6 ULP for *0 < x < 171.6*
+/// and 8 ULP for *-170 < x < 0*.
+/// 
 /// Ref: 『改訂新版 Cによる標準アルゴリズム事典』ガンマ関数 (gamma function) gamma.c
 /// 
 /// #### The value at the special points ####
@@ -77,7 +180,7 @@ fn test_the_values_of_log_gamma_at_non_finite_args(){
 /// assert_approximately(gamma(4.), 3. * 2. * 1.);
 /// assert_approximately(gamma(5.), 4. * 3. * 2. * 1.);
 /// 
-/// use f64::consts;
+/// use std::f64::consts;
 /// assert_approximately(gamma(0.5), consts::PI.sqrt());
 /// ```
 /// 
@@ -101,14 +204,93 @@ fn test_the_values_of_log_gamma_at_non_finite_args(){
 /// ```
 pub fn gamma(x: f64) -> f64 {
     if x > 0. {
-        log_gamma(x).exp()
+        gamma_positive(x)
     }else if x.is_integer() {
         f64::NAN
     }else{
-        PI / ((PI * x).sin() * log_gamma(1. - x).exp())
+        // Γ(x)Γ(-x) = -π/(x sin(πx)), where -x is exact unlike 1-x
+        (-PI / sin_pi(x)) / (x * gamma_positive(-x))
     }
 }
 
+const SQRT_2PI: f64 = 2.5066282746310002_f64;
+
+/// The argument above which *Γ(x)* is evaluated by the Stirling formula.
+const STIRLING_MIN: f64 = 10.;
+
+/// Coefficients of the rational approximation *Γ(x+2) ≈ P(x)/Q(x)* on *0 ≤ x < 1*
+/// (the highest degree first).
+///
+/// Ref: Cephes Math Library gamma.c
+#[allow(clippy::excessive_precision)]
+const GAMMA_P: [f64; 7] = [
+    1.60119522476751861407e-4,
+    1.19135147006586384913e-3,
+    1.04213797561761569935e-2,
+    4.76367800457137231464e-2,
+    2.07448227648435975150e-1,
+    4.94214826801497100753e-1,
+    9.99999999999999996796e-1
+];
+
+#[allow(clippy::excessive_precision)]
+const GAMMA_Q: [f64; 8] = [
+   -2.31581873324120129819e-5,
+    5.39605580493303397842e-4,
+   -4.45641913851797240494e-3,
+    1.18139785222060435552e-2,
+    3.58236398605498653373e-2,
+   -2.34591795718243348568e-1,
+    7.14304917030273074085e-2,
+    1.00000000000000000320e0
+];
+
+/// Return *Γ(x)* for positive *x* without going through *exp(log Γ(x))*.
+fn gamma_positive(mut x: f64) -> f64 {
+    if x.is_infinite() { return f64::INFINITY; }
+
+    if x >= STIRLING_MIN {
+        // x^{x-1/2} is split into two factors to avoid the overflow
+        let v = x.powf(0.5 * (x - 0.5));
+        return v * (v * (-x).exp() * (SQRT_2PI * stirling_series(x).exp()));
+    }
+
+    // reduce x to [2, 3) by the recurrence Γ(x+1) = xΓ(x)
+    let mut z = 1.;
+    while x >= 3. {
+        x -= 1.;
+        z *= x;
+    }
+    while x < 2. {
+        z /= x;
+        x += 1.;
+    }
+
+    let t = x - 2.;
+    let p = GAMMA_P.iter().fold(0., |acc, c| acc * t + c);
+    let q = GAMMA_Q.iter().fold(0., |acc, c| acc * t + c);
+    z * p / q
+}
+
+/// Return a value of the reciprocal gamma function *1/Γ(x)*, which is 0 at the non-positive integers.
+/// 
+/// ```
+/// use comonjo_mathfn::rgamma;
+/// 
+/// assert_eq!(rgamma(1.), 1.);
+/// assert_eq!(rgamma(0.), 0.);
+/// assert_eq!(rgamma(-1.), 0.);
+/// assert_eq!(rgamma(-2.), 0.);
+/// assert_eq!(rgamma(200.), 0.);  // underflow
+/// ```
+pub fn rgamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY { return f64::NAN; }
+    if x > 0. { return 1. / gamma_positive(x); }
+    if x.fract() == 0. { return 0.; }
+
+    -x * gamma_positive(-x) * (sin_pi(x) / PI)
+}
+
 #[cfg(test)]
 use super::test_util::*;
 
@@ -146,6 +328,92 @@ fn test_that_the_gamma_function_diverges_at_zero_or_negative_integers(){
     }
 }
 
+#[test]
+fn test_the_ulp_errors_of_gamma(){
+    // (x, Γ(x)) computed with 40 significant digits and rounded to the nearest f64
+    let values: [(f64, f64); 73] = [
+        (-144.7768615903452, -1.8384705364695225e-251),
+        (-134.91508843624717, -6.720202688112504e-230),
+        (-134.35589605976952, -3.0616817173727384e-229),
+        (-128.3216696370756, -2.0160261201870538e-216),
+        (-124.91240657388657, -9.376957541975124e-209),
+        (-118.36226907346924, -1.3091534703279275e-195),
+        (-117.38003401121935, 1.3900083235145464e-193),
+        (-100.28493979031663, -1.1593253026189772e-158),
+        (-99.30174283947063, 1.0338153923171772e-156),
+        (-90.34935305123027, -4.919598845462023e-139),
+        (-90.08457786671799, -5.50076832305622e-138),
+        (-85.28876505636201, 3.916068954626531e-129),
+        (-81.7103770919572, 3.0035803038687477e-122),
+        (-51.77978286339828, 1.4599041908915235e-67),
+        (-50.30983704223809, -3.7027250618958168e-65),
+        (-49.46407019081893, 8.481187170247177e-64),
+        (-48.17555309112075, -2.4425901864080254e-61),
+        (-46.28094706937132, -2.5112972737210424e-58),
+        (-46.195070458054715, -4.691608148324811e-58),
+        (-45.66878578332384, 2.3577898350150825e-57),
+        (-42.05958117762992, -9.609739718757529e-51),
+        (-31.86112347996834, 4.5804115394400125e-35),
+        (-21.77585548960795, 8.665928567757525e-21),
+        (-19.159294269997446, 3.351211710777717e-17),
+        (-6.247559882092361, -0.003893309318136835),
+        (-0.057269428613750506, -18.098353443329884),
+        (-1e-10, -10000000000.577215),
+        (1e-300, 9.999999999999999e+299),
+        (0.03077662426629657, 31.944584834445568),
+        (0.5848503687001028, 1.5250036849749173),
+        (1.2418351862815613, 0.9081236872303091),
+        (1.7022533963948534, 0.9090676695811868),
+        (2.011475736311281, 1.004906122410992),
+        (2.1261107077793095, 1.0600499337282505),
+        (2.1405707228960558, 1.0678247732368278),
+        (2.2910304519899984, 1.160474795111749),
+        (3.164541391111041, 2.3401585250954193),
+        (3.4529640371843087, 3.1564655269525463),
+        (3.494449155678685, 3.303079595734502),
+        (3.9030603565891253, 5.319279034962117),
+        (4.119104517936791, 6.982169801686795),
+        (4.165706594726544, 7.416771283948092),
+        (4.268068702100508, 8.486150178945259),
+        (7.435035864931781, 1649.4492057514713),
+        (7.628111714654962, 2404.0977927856125),
+        (8.678005517048772, 20363.4770697605),
+        (13.138402868230418, 679985783.4138094),
+        (16.542707142124137, 5842779432046.214),
+        (28.104808049724678, 1.541436255316242e+28),
+        (32.84977588004132, 1.5602666641369746e+35),
+        (42.819729271491695, 7.14976585479199e+50),
+        (46.39550150577683, 5.4238248245635486e+56),
+        (47.26533825211649, 1.5252432292365385e+58),
+        (58.76636013580867, 9.08856223584492e+77),
+        (61.58657645002479, 9.258581173275307e+82),
+        (64.50117071730634, 1.5907181029795046e+88),
+        (69.09882127936538, 3.766123164875488e+96),
+        (72.58310182080265, 1.0278848782932485e+103),
+        (78.04155025461104, 1.7394901337895963e+113),
+        (85.13769980650846, 6.106056471620234e+126),
+        (89.72403135471575, 4.7778846958669624e+135),
+        (108.04255409022961, 1.4966600605785944e+172),
+        (139.98802671695773, 9.063680146464754e+238),
+        (143.78700562119934, 1.3385193338846533e+247),
+        (145.18059992120166, 1.3628155752537886e+250),
+        (146.38372503602113, 5.4431279481856685e+252),
+        (147.57676445157065, 2.087891361636185e+255),
+        (149.85063438590439, 1.8030917638282324e+260),
+        (161.9123298354303, 4.860730508394352e+286),
+        (165.19191523298042, 8.753733234999449e+293),
+        (170.80594019759508, 2.6775763392727325e+306),
+        (171.4650850890654, 7.924999618171124e+307),
+        (171.6, 1.5858969096672565e+308),
+    ];
+
+    for (x, exp) in values {
+        let max_ulp = if x > 0. { 6 }else{ 8 };
+        assert!(ulp_distance(gamma(x), exp) <= max_ulp,
+            "|Γ(x) - {}| <= {} ULP at x = {}: {}", exp, max_ulp, x, gamma(x));
+    }
+}
+
 #[test]
 fn test_the_values_of_gamma_at_non_finite_args(){
     assert_eq!(gamma(f64::INFINITY), f64::INFINITY, "Γ(∞) = ∞");
@@ -169,4 +437,242 @@ fn test_the_gamma_function_properties(){
             |x| PI / (PI * x).sin())
         .filter(|x| !is_close_to_an_integer(x, DELTA))
         .epsilon(1e-11).assert();
-}
\ No newline at end of file
+}
+
+/// Return a value of the polygamma function *ψ⁽ⁿ⁾(x)*, the *(n+1)*-th derivative of *log Γ(x)*.
+/// 
+/// #### The value at the special points ####
+/// 
+/// ```
+/// use comonjo_mathfn::polygamma;
+/// 
+/// fn assert_approximately(x: f64, y: f64){
+///     assert!((x - y).abs() <= 1e-14);
+/// }
+/// 
+/// const EULER_GAMMA: f64 = 0.5772156649015329;
+/// const ZETA3: f64 = 1.2020569031595942;
+/// 
+/// assert_approximately(polygamma(0, 1.), -EULER_GAMMA);
+/// assert_approximately(polygamma(1, 1.), std::f64::consts::PI.powi(2) / 6.);
+/// assert_approximately(polygamma(2, 1.), -2. * ZETA3);
+/// ```
+/// 
+/// #### Diverging points ####
+/// ```
+/// use comonjo_mathfn::polygamma;
+/// 
+/// assert!(polygamma(0, 0.).is_nan());
+/// assert!(polygamma(1, -1.).is_nan());
+/// assert!(polygamma(2, -2.).is_nan());
+/// ```
+pub fn polygamma(n: u32, mut x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN; }
+    if x.is_infinite() {
+        return if x < 0. { f64::NAN }else if n == 0 { f64::INFINITY }else{ 0. };
+    }
+    if x <= 0. && x.fract() == 0. { return f64::NAN; }
+    if x < 0. { return polygamma_reflected(n, x); }
+
+    let nf = n as f64;
+    let n_factorial: f64 = (1..=n).map(|k| k as f64).product();
+
+    // shift x up so that the asymptotic expansion is accurate enough
+    let mut shift_sum = 0.;
+    while x < N + 2. * nf {
+        shift_sum += x.powi(-(n as i32) - 1);
+        x += 1.;
+    }
+
+    let w = 1. / (x * x);
+    if n == 0 {
+        let mut series = 0.;
+        let mut wk = 1.;
+        for (k, b) in BERNOULLI.iter().enumerate() {
+            wk *= w;
+            series += b / (2. * (k + 1) as f64) * wk;
+        }
+        x.ln() - 0.5 / x - series - shift_sum
+
+    }else{
+        // coefficient: B_2k (2k+n-1)!/((2k)!(n-1)!)
+        let mut series = 1. + 0.5 * nf / x;
+        let mut coeff = 1.;
+        let mut wk = 1.;
+        for (k, b) in BERNOULLI.iter().enumerate() {
+            let k2 = 2. * (k + 1) as f64;
+            coeff *= (k2 + nf - 2.) * (k2 + nf - 1.) / ((k2 - 1.) * k2);
+            wk *= w;
+            series += b * coeff * wk;
+        }
+        let sign = if n % 2 == 1 { 1. }else{ -1. };
+        sign * (n_factorial / nf * x.powi(-(n as i32)) * series + n_factorial * shift_sum)
+    }
+}
+
+/// Evaluate *ψ⁽ⁿ⁾(x) = (-1)ⁿψ⁽ⁿ⁾(1-x) - π dⁿ/dxⁿ cot(πx)* for negative *x*.
+/// The derivative of *cot(πx)* is expanded as a polynomial of *cot(πx)*.
+fn polygamma_reflected(n: u32, x: f64) -> f64 {
+    // coefficients of P_k(c), where dᵏ/dxᵏ cot(πx) = πᵏ P_k(cot(πx))
+    let mut p = vec![0., 1.];
+    for _ in 0..n {
+        // P_{k+1}(c) = -(1 + c²)P_k'(c)
+        let mut next = vec![0.; p.len() + 1];
+        for (i, a) in p.iter().enumerate().skip(1) {
+            let da = i as f64 * a;
+            next[i - 1] -= da;
+            next[i + 1] -= da;
+        }
+        p = next;
+    }
+
+    let c = cot_pi(x);
+    let cot_n = p.iter().rev().fold(0., |acc, a| acc * c + a);
+    let sign = if n % 2 == 1 { -1. }else{ 1. };
+    sign * polygamma(n, 1. - x) - PI.powi(n as i32 + 1) * cot_n
+}
+
+/// Return *cot(πx)* with the argument reduced to *[-1/2, 1/2]*,
+/// so that the zeros at half integers are kept accurate.
+fn cot_pi(x: f64) -> f64 {
+    let r = x - x.round();
+    if r.abs() <= 0.25 {
+        1. / (PI * r).tan()
+    }else{
+        (PI * (0.5_f64.copysign(r) - r)).tan()
+    }
+}
+
+/// Return a value of the digamma function *ψ(x) = d/dx log Γ(x)*.
+pub fn digamma(x: f64) -> f64 {
+    polygamma(0, x)
+}
+
+/// Return a value of the trigamma function *ψ⁽¹⁾(x) = d²/dx² log Γ(x)*.
+pub fn trigamma(x: f64) -> f64 {
+    polygamma(1, x)
+}
+
+#[test]
+fn test_the_values_of_polygamma_at_the_specific_points(){
+    const EULER_GAMMA: f64 = 0.5772156649015329;
+    const LN_2: f64 = std::f64::consts::LN_2;
+
+    assert_approximately(digamma(1.), -EULER_GAMMA, EPS, "ψ(1) = -γ");
+    assert_approximately(digamma(0.5), -EULER_GAMMA - 2. * LN_2, EPS, "ψ(1/2) = -γ - 2ln2");
+    assert_approximately(digamma(-0.5), -EULER_GAMMA - 2. * LN_2 + 2., EPS, "ψ(-1/2) = ψ(1/2) + 2");
+
+    assert_approximately(trigamma(1.), PI * PI / 6., EPS, "ψ⁽¹⁾(1) = π²/6");
+    assert_approximately(trigamma(0.5), PI * PI / 2., EPS, "ψ⁽¹⁾(1/2) = π²/2");
+
+    assert_approximately(polygamma(3, 1.), PI.powi(4) / 15., EPS, "ψ⁽³⁾(1) = π⁴/15");
+    assert_approximately(polygamma(3, 0.5), PI.powi(4), EPS, "ψ⁽³⁾(1/2) = π⁴");
+
+    // ψ(x) ~ -1/x near the pole 0
+    assert_approximately(digamma(-1e-20) / 1e20, 1., EPS, "ψ(-1e-20) = 1e20");
+}
+
+#[test]
+fn test_the_values_of_polygamma_at_non_finite_args(){
+    assert_eq!(digamma(f64::INFINITY), f64::INFINITY, "ψ(∞) = ∞");
+    assert_eq!(trigamma(f64::INFINITY), 0.,           "ψ⁽¹⁾(∞) = 0");
+    assert!(digamma(f64::NEG_INFINITY).is_nan(),      "ψ(-∞) = NaN");
+    assert!(digamma(f64::NAN).is_nan(),               "ψ(NaN) = NaN");
+}
+
+#[test]
+fn test_the_polygamma_function_properties(){
+    const DELTA: f64 = 1e-3;
+
+    should_the_same_mathfn(
+        "ψ(x + 1) = ψ(x) + 1/x",
+            |x| digamma(x + 1.),
+            |x| digamma(x) + 1. / x)
+        .filter(|x| !is_close_to_a_non_positive_integer(x, DELTA)).assert();
+
+    should_the_same_mathfn(
+        "ψ(1 - x) - ψ(x) = π cot(πx)",
+            |x| digamma(1. - x) - digamma(x),
+            |x| PI / (PI * x).tan())
+        .filter(|x| !is_close_to_an_integer(x, DELTA))
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn(
+        "ψ(2x) = (ψ(x) + ψ(x + 1/2))/2 + ln2",
+            |x| digamma(2. * x),
+            |x| 0.5 * (digamma(x) + digamma(x + 0.5)) + std::f64::consts::LN_2)
+        .filter(|x| !is_close_to_a_non_positive_integer(2. * x, DELTA)).assert();
+
+    should_the_same_mathfn(
+        "ψ⁽¹⁾(x + 1) = ψ⁽¹⁾(x) - 1/x²",
+            |x| trigamma(x + 1.),
+            |x| trigamma(x) - 1. / (x * x))
+        .filter(|x| !is_close_to_a_non_positive_integer(x, 0.1)).assert();
+
+    should_the_same_mathfn(
+        "ψ⁽¹⁾(1 - x) + ψ⁽¹⁾(x) = π²/sin²(πx)",
+            |x| trigamma(1. - x) + trigamma(x),
+            |x| (PI / (PI * x).sin()).powi(2))
+        .filter(|x| !is_close_to_an_integer(x, DELTA)).assert();
+
+    should_the_same_mathfn2(
+        "ψ⁽ⁿ⁾(x + 1) = ψ⁽ⁿ⁾(x) + (-1)ⁿn!/xⁿ⁺¹",
+            |n, x| polygamma(n as u32, x + 1.),
+            |n, x| {
+                let n_factorial: f64 = (1..=n as u32).map(|k| k as f64).product();
+                polygamma(n as u32, x) + (-1_f64).powi(n as i32) * n_factorial / x.powi(n as i32 + 1)
+            })
+        .var0(|v| v.name("n").range(0., 6.).is_integer(true).end())
+        .var1(|v| v.name("x").range(0.5, 10.).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "ψ⁽ⁿ⁾(x) = (d/dx)ψ⁽ⁿ⁻¹⁾(x)",
+            |n, x| polygamma(n as u32, x),
+            |n, x| {
+                let h = 1e-5;
+                (polygamma(n as u32 - 1, x + h) - polygamma(n as u32 - 1, x - h)) / (2. * h)
+            })
+        .var0(|v| v.name("n").range(1., 4.).is_integer(true).end())
+        .var1(|v| v.name("x").range(1., 10.).end())
+        .epsilon(1e-7).assert();
+}
+
+#[test]
+fn test_the_values_of_rgamma_at_the_specific_points(){
+    for n in 0..=200 {
+        assert_eq!(rgamma(-n as f64), 0., "1/Γ(-n) = 0 at -n = {}", -n);
+    }
+
+    // computed with 40 significant digits
+    assert!(ulp_distance(rgamma(-170.5), -3.018649650835054e307) <= 8, "1/Γ(-170.5)");
+    assert!(ulp_distance(rgamma(-150.3), -6.623570165514415e262) <= 8, "1/Γ(-150.3)");
+    assert!(ulp_distance(rgamma(-0.999999), -9.999995772431874e-7) <= 8, "1/Γ(-0.999999)");
+    assert!(ulp_distance(rgamma(1e-300), 1e-300) <= 2, "1/Γ(x) ~ x (x → 0)");
+    assert!(ulp_distance(rgamma(-1e-300), -1e-300) <= 2, "1/Γ(x) ~ x (x → 0)");
+
+    assert_eq!(rgamma(f64::INFINITY), 0., "1/Γ(∞) = 0");
+    assert!(rgamma(f64::NEG_INFINITY).is_nan(), "1/Γ(-∞) = NaN");
+    assert!(rgamma(f64::NAN).is_nan(), "1/Γ(NaN) = NaN");
+}
+
+#[test]
+fn test_the_reciprocal_gamma_function_properties(){
+    should_the_same_mathfn(
+        "1/Γ(x) = 1/(Γ(x))",
+            rgamma,
+            |x| 1. / gamma(x))
+        .filter(|x| !is_close_to_a_non_positive_integer(x, 1e-3)).assert();
+
+    should_the_same_mathfn(
+        "1/Γ(x) = x/Γ(x+1)",
+            rgamma,
+            |x| x * rgamma(x + 1.))
+        .var0(|v| v.range(-170., 5.).end()).assert();
+
+    should_the_same_mathfn(
+        "1/(Γ(x)Γ(1-x)) = sin(πx)/π",
+            |x| rgamma(x) * rgamma(1. - x),
+            |x| (PI * x).sin() / PI)
+        .var0(|v| v.range(-5., 5.).end()).assert();
+}
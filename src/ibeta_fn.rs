@@ -0,0 +1,717 @@
+// The tests refer to
+// <a href="https://en.m.wikipedia.org/wiki/Beta_function#Incomplete_beta_function">Incomplete beta function</a>
+
+use crate::beta_fn::log_beta;
+use crate::gamma_fn::{N, log_gamma, stirling_series};
+use crate::igamma_fn::{hyper_geometric_series, log1pmx, max_iterations, q_gamma, p_chi2, q_chi2};
+
+const FPMIN: f64 = 1e-300;
+
+/// Return a value of the regularized incomplete beta function *I_x(a, b)*.
+/// The normalization factor *log Β(a, b)* can be manually specified by the last argument.
+fn p_beta_normalizable(a: f64, b: f64, x: f64, log_beta_ab: f64) -> f64 {
+    p_beta_normalizable_xy(a, b, x, 1. - x, log_beta_ab)
+}
+
+/// Return a value of the regularized incomplete beta function *I_x(a, b)*,
+/// where *y = 1 - x* is given to keep the precision when *x* is close to 1.
+pub(crate) fn p_beta_normalizable_xy(a: f64, b: f64, x: f64, y: f64, log_beta_ab: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || x.is_nan() || y.is_nan() { return f64::NAN; }
+    if a <= 0. || b <= 0. || !(0. ..=1.).contains(&x) { return f64::NAN; }
+    if x == 0. { return 0.; }
+    if y == 0. { return 1.; }
+    if x * (a + b + 2.) > a + 1. { return 1. - p_beta_normalizable_xy(b, a, y, x, log_beta_ab); }
+
+    let log_x = if y < 0.5 { (-y).ln_1p() }else{ x.ln() };
+    if a >= 15. * b.max(1.) && -log_x * b.max(1.) <= 1. {
+        let result = p_beta_asymptotic_in_a(a, b, -log_x, log_beta_ab);
+        if !result.is_nan() { return result; }
+    }
+
+    let log_y = if x < 0.5 { (-x).ln_1p() }else{ y.ln() };
+    let front = log_power_by_beta(a, b, x, y, log_x, log_y, log_beta_ab).exp() / a;
+
+    if (a + b) * x <= 0.5 * (a + 1.) {
+        hyper_geometric_series(front, |k| (a + b + k - 1.) * x / (a + k), 1., max_iterations(a.min(b)))
+    }else{
+        front * beta_continued_fraction(a, b, x)
+    }
+}
+
+/// Return *log(x^a y^b/Β(a, b))*, which is evaluated by the Stirling series for large *a* and *b*
+/// (*log_beta_ab* is not used in this case).
+fn log_power_by_beta(a: f64, b: f64, x: f64, y: f64, log_x: f64, log_y: f64, log_beta_ab: f64) -> f64 {
+    if a.min(b) >= N && a.max(b).is_finite() {
+        // log(1+t) - t, where 1 + t = x/x₀ or y/y₀
+        fn log_ratio_minus_t(t: f64, log_ratio: f64) -> f64 {
+            if t.abs() <= 0.5 { log1pmx(t) }else{ log_ratio - t }
+        }
+
+        let ab = a + b;
+        // x/x₀ - 1 = (bx - ay)/a and y/y₀ - 1 = (ay - bx)/b, so that the linear terms cancel out (at + bu = 0)
+        let d = x * b - y * a;
+        let main = a * log_ratio_minus_t(d / a, log_x + (ab / a).ln())
+            + b * log_ratio_minus_t(-d / b, log_y + (ab / b).ln());
+        let corr = stirling_series(a) + stirling_series(b) - stirling_series(ab);
+        main + 0.5 * (a * b / (ab * std::f64::consts::TAU)).ln() - corr
+    }else{
+        a * log_x + b * log_y - log_beta_ab
+    }
+}
+
+/// Return *I_x(a, b)* for large *a* and small *b* by the asymptotic expansion in *ξ = -log x*.
+/// 
+/// Ref: A. R. DiDonato and A. H. Morris, Algorithm 708 (BGRAT) (1992)
+fn p_beta_asymptotic_in_a(a: f64, b: f64, xi: f64, log_beta_ab: f64) -> f64 {
+    const N: usize = 60;
+
+    // (1-e^{-s})/s = Σ_j (-1)^j s^j/(j+1)!
+    let mut f = [1.; N];
+    for j in 1..N { f[j] = -f[j-1] / (j + 1) as f64; }
+
+    let y = a * xi;
+    let log_a = a.ln();
+    let mut c = [1.; N];
+    let mut sum = 0.;
+    for k in 0..N {
+        // the coefficients of the power of the series (c_k = Σ_{j=1}^k (bj - k) f_j c_{k-j} / k)
+        if k > 0 {
+            c[k] = (1..=k).map(|j| (b * j as f64 - k as f64) * f[j] * c[k-j]).sum::<f64>() / k as f64;
+        }
+        let bk = b + k as f64;
+        let term = c[k] * (log_gamma(bk) - bk * log_a - log_beta_ab).exp() * q_gamma(bk, y);
+        sum += term;
+        if term.abs() <= f64::EPSILON * sum.abs() { return sum; }
+    }
+
+    f64::NAN
+}
+
+/// Evaluate the continued fraction of *I_x(a, b)* by the modified Lentz method.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    fn not_too_small(t: f64) -> f64 {
+        if t.abs() < FPMIN { FPMIN }else{ t }
+    }
+
+    let mut c = 1.;
+    let mut d = 1. / not_too_small(1. - (a + b) * x / (a + 1.));
+    let mut result = d;
+    let mut m = 1.;
+    let m_max = max_iterations(a.min(b));
+    while m < m_max {
+        let m2 = 2. * m;
+
+        // even step
+        let aa = m * (b - m) * x / ((a - 1. + m2) * (a + m2));
+        d = 1. / not_too_small(1. + aa * d);
+        c = not_too_small(1. + aa / c);
+        result *= d * c;
+
+        // odd step
+        let aa = -(a + m) * (a + b + m) * x / ((a + m2) * (a + 1. + m2));
+        d = 1. / not_too_small(1. + aa * d);
+        c = not_too_small(1. + aa / c);
+        let delta = d * c;
+        result *= delta;
+
+        if (delta - 1.).abs() <= f64::EPSILON { return result; }
+        m += 1.;
+    }
+
+    f64::NAN
+}
+
+/// Return a value of the complement *1 - I_x(a, b)* of the regularized incomplete beta function.
+/// The normalization factor *log Β(a, b)* can be manually specified by the last argument.
+fn q_beta_normalizable(a: f64, b: f64, x: f64, log_beta_ab: f64) -> f64 {
+    p_beta_normalizable(b, a, 1. - x, log_beta_ab)
+}
+
+//***** incomplete beta function *****
+/// Return a value of the lower incomplete beta function *Β(x; a, b)*.
+/// (The arguments *a* and *b* must be positive and *0 ≤ x ≤ 1*.)
+pub fn ibeta(a: f64, b: f64, x: f64) -> f64 {
+    let log_beta_ab = log_beta(a, b);
+    log_beta_ab.exp() * p_beta_normalizable(a, b, x, log_beta_ab)
+}
+
+/// Return a value of the upper incomplete beta function *Β(a, b) - Β(x; a, b)*.
+/// (The arguments *a* and *b* must be positive and *0 ≤ x ≤ 1*.)
+#[allow(non_snake_case)]
+pub fn iBeta(a: f64, b: f64, x: f64) -> f64 {
+    let log_beta_ab = log_beta(a, b);
+    log_beta_ab.exp() * q_beta_normalizable(a, b, x, log_beta_ab)
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::beta_fn::beta;
+
+#[test]
+fn test_the_values_of_ibeta_at_the_end_points(){
+    repeat(10, ||{
+        let a = rand(0.1, 10.);
+        let b = rand(0.1, 10.);
+        assert_eq!(ibeta(a, b, 0.), 0., "Β(0; a, b) = 0 at (a, b) = ({}, {})", a, b);
+        assert_approximately(ibeta(a, b, 1.), beta(a, b), EPS,
+            &format!("Β(1; a, b) = Β(a, b) at (a, b) = ({}, {})", a, b));
+        assert_approximately(iBeta(a, b, 0.), beta(a, b), EPS,
+            &format!("Β(a, b) - Β(0; a, b) = Β(a, b) at (a, b) = ({}, {})", a, b));
+    });
+}
+
+#[test]
+fn test_the_values_of_ibeta_out_of_the_domain(){
+    for (a, b) in non_finite_args2_with([-1., 0., 0.5], [0.5, 0., -1.]){
+        assert!(ibeta(a, b, 0.5).is_nan(), "Β(0.5; {}, {}) = NaN", a, b);
+    }
+
+    for x in non_finite_values_with([-0.5, 1.5]){
+        assert!(ibeta(2., 3., x).is_nan(), "Β({}; 2, 3) = NaN", x);
+    }
+}
+
+#[test]
+fn test_the_incomplete_beta_function_properties(){
+    should_the_same_mathfn3(
+        "Β(x; a, b) + Β(a, b) - Β(x; a, b) = Β(a, b)",
+            |a, b, x| ibeta(a, b, x) + iBeta(a, b, x),
+            |a, b, _| beta(a, b))
+        .var0(|v| v.name("a").range(0.1, 10.).n(20).end())
+        .var1(|v| v.name("b").range(0.1, 10.).n(20).end())
+        .var2(|v| v.name("x").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn2(
+        "Β(x; a, 1) = x^a/a",
+            |a, x| ibeta(a, 1., x),
+            |a, x| x.powf(a) / a)
+        .var0(|v| v.name("a").range(0.1, 10.).end())
+        .var1(|v| v.name("x").range(0., 1.).end()).assert();
+
+    should_the_same_mathfn2(
+        "Β(x; 1, b) = (1 - (1-x)^b)/b",
+            |b, x| ibeta(1., b, x),
+            |b, x| (1. - (1. - x).powf(b)) / b)
+        .var0(|v| v.name("b").range(0.1, 10.).end())
+        .var1(|v| v.name("x").range(0., 1.).end()).assert();
+}
+
+/// Return a value of the regularized incomplete beta function *I_x(a, b) = Β(x; a, b)/Β(a, b)*.
+pub fn p_beta(a: f64, b: f64, x: f64) -> f64 {
+    p_beta_normalizable(a, b, x, log_beta(a, b))
+}
+
+/// Return a value of the complement of the regularized incomplete beta function *1 - I_x(a, b)*.
+pub fn q_beta(a: f64, b: f64, x: f64) -> f64 {
+    q_beta_normalizable(a, b, x, log_beta(a, b))
+}
+
+#[test]
+fn test_the_regularized_incomplete_beta_function_properties(){
+
+    should_the_same_mathfn3(
+        "I_x(a, b) = Β(x; a, b)/Β(a, b)",
+            p_beta,
+            |a, b, x| ibeta(a, b, x) / beta(a, b))
+        .var0(|v| v.name("a").range(0.1, 10.).n(20).end())
+        .var1(|v| v.name("b").range(0.1, 10.).n(20).end())
+        .var2(|v| v.name("x").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "I_x(a, b) = 1 - I_{1-x}(b, a)",
+            p_beta,
+            |a, b, x| 1. - p_beta(b, a, 1. - x))
+        .var0(|v| v.name("a").range(0.1, 100.).n(20).end())
+        .var1(|v| v.name("b").range(0.1, 100.).n(20).end())
+        .var2(|v| v.name("x").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_beta(a, b, x) = 1 - p_beta(a, b, x)",
+            q_beta,
+            |a, b, x| 1. - p_beta(a, b, x))
+        .var0(|v| v.name("a").range(0.1, 100.).n(20).end())
+        .var1(|v| v.name("b").range(0.1, 100.).n(20).end())
+        .var2(|v| v.name("x").range(0., 1.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "I_x(a+1, b) = I_x(a, b) - x^a(1-x)^b/(aΒ(a, b))",
+            |a, b, x| p_beta(a + 1., b, x),
+            |a, b, x| p_beta(a, b, x) - x.powf(a) * (1. - x).powf(b) / (a * beta(a, b)))
+        .var0(|v| v.name("a").range(0.1, 10.).n(20).end())
+        .var1(|v| v.name("b").range(0.1, 10.).n(20).end())
+        .var2(|v| v.name("x").range(0., 1.).n(20).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn3(
+        "I_x(a, n-a+1) = Σ_{j=a}^n C(n, j)x^j(1-x)^{n-j}",
+            |a, n, x| p_beta(a, n - a + 1., x),
+            |a, n, x| {
+                let (a, n) = (a.round() as i32, n.round() as i32);
+                let mut sum = 0.;
+                let mut binom = 1.;  // C(n, j)
+                for j in 0..=n {
+                    if j >= a { sum += binom * x.powi(j) * (1. - x).powi(n - j); }
+                    binom *= (n - j) as f64 / (j + 1) as f64;
+                }
+                sum
+            })
+        .filter(|a, n, _| a <= n)
+        .var0(|v| v.name("a").range(1., 10.).is_integer(true).end())
+        .var1(|v| v.name("n").range(1., 10.).is_integer(true).end())
+        .var2(|v| v.name("x").range(0., 1.).is_integer(false).n(20).end()).assert();
+
+    // the values by mpmath (large a and small b)
+    assert_reference_values("p_beta", |(a, b, x)| p_beta(a, b, x), &[
+        ((50.,   0.5, 0.95),    0.02387270549699075),
+        ((1000., 0.3, 0.997),   0.006463758751356762),
+        ((20.,   0.75, 0.5),    4.306847456112855e-07),
+        ((1e5,   0.5, 0.99999), 0.1572986881624258)], 1e-14);
+
+    // the values by mpmath (large a and b > 1 with bξ ≤ 1),
+    // where the continued fraction loses up to 1e-10 of the relative accuracy
+    let values = [
+        ((1e6,  2.5, 0.9999975),    0.41587896657590273, 0.5841210334240973),
+        ((1e6,  2.5, 0.999995),     0.07523432527410284, 0.9247656747258972),
+        ((1e8,  3.,  0.99999998),   0.6766764056411788,  0.32332359435882124),
+        ((1e8,  3.,  0.99999995),   0.12465200449794331, 0.8753479955020567),
+        ((1e10, 5.5, 0.9999999994), 0.363643145411176,   0.636356854588824),
+        ((1e4,  20., 0.998),        0.46679262840632546, 0.5332073715936745)];
+    assert_reference_values("p_beta", |(a, b, x)| p_beta(a, b, x), &values.map(|(args, p, _)| (args, p)), 1e-13);
+    assert_reference_values("q_beta", |(a, b, x)| q_beta(a, b, x), &values.map(|(args, _, q)| (args, q)), 1e-13);
+}
+
+//***** Student's t distribution *****
+/// Return the two-sided tail probability *P(|T| > x)* of Student's t distribution for *x ≥ 0*,
+/// that is, *I_{ν/(ν+x²)}(ν/2, 1/2)*.
+fn two_sided_tail_of_t(x: f64, nu: f64) -> f64 {
+    if x.is_infinite() { return 0.; }
+    let a = 0.5 * nu;
+    let log_beta_ab = log_beta(a, 0.5);
+    // both ν/(ν+x²) and x²/(ν+x²) are given since either can be close to 1,
+    // and they are built from t = x/√ν or r = √ν/x so that x² doesn't overflow
+    let t = x / nu.sqrt();
+    let (y, z) = if t <= 1. {
+        let t2 = t * t;
+        (1. / (1. + t2), t2 / (1. + t2))
+    }else{
+        let log_r = 0.5 * nu.ln() - x.ln();
+        let r2 = (2. * log_r).exp();
+        // I_y(a, 1/2) = y^a/(a Β(a, 1/2)) (1 + O(y)), where y = r² may underflow
+        if r2 < f64::EPSILON { return (nu * log_r - a.ln() - log_beta_ab).exp(); }
+        (r2 / (1. + r2), 1. / (1. + r2))
+    };
+    p_beta_normalizable_xy(a, 0.5, y, z, log_beta_ab)
+}
+
+/// Return the upper tail probability *P(T > x)* of Student's t distribution for *x ≥ 0*.
+pub(crate) fn upper_tail_of_t(x: f64, nu: f64) -> f64 {
+    if nu == 1. {
+        // Cauchy distribution: atan(1/x)/π
+        (1. / x).atan() * std::f64::consts::FRAC_1_PI
+    }else if nu == 2. {
+        // (1 - x/√(2+x²))/2 = 1/(√(2+x²)(√(2+x²)+x))
+        if x.is_infinite() { return 0.; }
+        let s = (2. + x * x).sqrt();
+        if s.is_infinite() { 1. / (x * x) }else{ 1. / (s * (s + x)) }
+    }else if nu.is_infinite() {
+        crate::igamma_fn::q_normal(x)
+    }else{
+        0.5 * two_sided_tail_of_t(x, nu)
+    }
+}
+
+/// Return a value of the lower CDF of Student's t distribution with *ν* degrees of freedom.
+/// 
+/// ```
+/// use comonjo_mathfn::p_t;
+/// 
+/// assert_eq!(p_t(0., 3.), 0.5);
+/// assert!((p_t(1., 3.) - 0.8044988905221147).abs() <= 1e-15);
+/// assert!((p_t(1., 1.) - 0.75).abs() <= 1e-15);
+/// ```
+pub fn p_t(x: f64, nu: f64) -> f64 {
+    if x.is_nan() || nu.is_nan() || nu <= 0. { return f64::NAN; }
+    if x == 0. { return 0.5; }
+    if x > 0. {
+        1. - upper_tail_of_t(x, nu)
+    }else{
+        upper_tail_of_t(-x, nu)
+    }
+}
+
+/// Return a value of the upper CDF of Student's t distribution with *ν* degrees of freedom.
+/// 
+/// ```
+/// use comonjo_mathfn::q_t;
+/// 
+/// assert!((q_t(30., 5.) / 3.859324310248026e-7 - 1.).abs() <= 1e-14);
+/// ```
+pub fn q_t(x: f64, nu: f64) -> f64 {
+    p_t(-x, nu)
+}
+
+#[cfg(test)]
+use crate::igamma_fn::p_normal;
+
+#[test]
+fn test_the_values_of_p_t_out_of_the_domain(){
+    for (x, nu) in non_finite_args2_with(0.5, [-1., 0.]){
+        if !x.is_nan() && !nu.is_nan() && nu > 0. { continue; }
+        assert!(p_t(x, nu).is_nan(), "p_t({}, {}) = NaN", x, nu);
+        assert!(q_t(x, nu).is_nan(), "q_t({}, {}) = NaN", x, nu);
+    }
+}
+
+#[test]
+fn test_the_t_distribution_cdf_properties(){
+    should_the_same_mathfn2(
+        "q_t(x, ν) = 1 - p_t(x, ν)",
+            q_t,
+            |x, nu| 1. - p_t(x, nu))
+        .var1(|v| v.name("ν").range(0.1, 50.).end()).assert();
+
+    should_the_same_mathfn2(
+        "p_t(-x, ν) = q_t(x, ν)",
+            |x, nu| p_t(-x, nu),
+            q_t)
+        .var1(|v| v.name("ν").range(0.1, 50.).end()).assert();
+
+    should_the_same_mathfn2(
+        "p_t(x, ν) = 1 - I_{ν/(ν+x²)}(ν/2, 1/2)/2",
+            p_t,
+            |x, nu| 1. - 0.5 * p_beta(0.5 * nu, 0.5, nu / (nu + x * x)))
+        .filter(|x, _| x >= 1.)
+        .var1(|v| v.name("ν").range(0.1, 50.).end()).assert();
+
+    should_the_same_mathfn(
+        "p_t(x, 1) = 1 - I_{1/(1+x²)}(1/2, 1/2)/2",
+            |x| p_t(x, 1.),
+            |x| 1. - 0.5 * p_beta(0.5, 0.5, 1. / (1. + x * x)))
+        .filter(|x| x >= 0.).assert();
+
+    should_the_same_mathfn(
+        "p_t(x, 2) = 1 - I_{2/(2+x²)}(1, 1/2)/2",
+            |x| p_t(x, 2.),
+            |x| 1. - 0.5 * p_beta(1., 0.5, 2. / (2. + x * x)))
+        .filter(|x| x >= 0.).assert();
+
+    should_the_same_mathfn(
+        "p_t(x, 2) = 1/2 + x/(2√(2+x²))",
+            |x| p_t(x, 2.),
+            |x| 0.5 + x / (2. * (2. + x * x).sqrt())).assert();
+
+    // the values by mpmath
+    assert_reference_values("q_t", |(x, nu)| q_t(x, nu), &[
+        (( 2.5, 10.), 0.015723422118304402),
+        ((-1.5, 4.5), 0.8998904571719233),
+        ((30.,  5.),  3.859324310248026e-07),
+        ((1e10, 3.),  1.102657790843584e-30),
+        (( 0.1, 0.5), 0.4731658105643032),
+        (( 3.,  1e6), 0.0013499312707108985),
+        ((1e200, 1e-3), 0.3141732124861151)], 1e-13);
+    // x² overflows, and the rounding error of log x is amplified by ν/2
+    assert_reference_values("q_t", |(x, nu)| q_t(x, nu), &[((1e200, 1.5), 3.770852432016246e-301)], 1e-12);
+    assert_reference_values("p_t", |(x, nu)| p_t(x, nu), &[((-1e200, 1.5), 3.770852432016246e-301)], 1e-12);
+}
+
+#[test]
+fn test_that_the_t_distribution_converges_to_the_normal_distribution(){
+    should_the_same_mathfn(
+        "p_t(x, ∞) = p_normal(x)",
+            |x| p_t(x, f64::INFINITY),
+            p_normal).assert();
+
+    // p_t(x, ν) - p_normal(x) ~ -(x³ + x)φ(x)/(4ν)
+    for nu in [1e6, 1e8, 1e10, 1e12] {
+        should_the_same_mathfn(
+            "p_t(x, ν) → p_normal(x) as ν → ∞",
+                |x| p_t(x, nu),
+                p_normal)
+            .var0(|v| v.range(-5., 5.).n(100).end())
+            .epsilon(1. / nu).assert();
+    }
+}
+
+//***** F distribution *****
+/// Return the lower CDF *I_u(d1/2, d2/2)* (or the upper CDF *I_v(d2/2, d1/2)* if `upper` is true)
+/// of the F distribution for *x > 0*, where *u = d1x/(d1x+d2)* and *v = d2/(d1x+d2)*.
+fn f_distribution_cdfs(x: f64, d1: f64, d2: f64, upper: bool) -> f64 {
+    if d1.is_infinite() || d2.is_infinite() {
+        // F → χ²(d1)/d1 as d2 → ∞, and F → d2/χ²(d2) as d1 → ∞
+        let p = if d1.is_infinite() && d2.is_infinite() {
+            if x < 1. { 0. }else{ 1. }
+        }else if d2.is_infinite() {
+            return if upper { q_chi2(d1 * x, d1) }else{ p_chi2(d1 * x, d1) };
+        }else{
+            return if upper { p_chi2(d2 / x, d2) }else{ q_chi2(d2 / x, d2) };
+        };
+        return if upper { 1. - p }else{ p };
+    }
+
+    // the ratio d2/(d1x) is used for large x to avoid the overflow of d1x
+    let r = d1 * x / d2;
+    let (u, v) = if r <= 1. {
+        (r / (1. + r), 1. / (1. + r))
+    }else{
+        let s = d2 / d1 / x;
+        (1. / (1. + s), s / (1. + s))
+    };
+    let log_beta_ab = log_beta(0.5 * d1, 0.5 * d2);
+    if upper {
+        p_beta_normalizable_xy(0.5 * d2, 0.5 * d1, v, u, log_beta_ab)
+    }else{
+        p_beta_normalizable_xy(0.5 * d1, 0.5 * d2, u, v, log_beta_ab)
+    }
+}
+
+fn is_out_of_domain_of_f(x: f64, d1: f64, d2: f64) -> bool {
+    x.is_nan() || d1.is_nan() || d2.is_nan() || d1 <= 0. || d2 <= 0.
+}
+
+/// Return a value of the lower CDF of the F distribution
+/// with *d1* and *d2* degrees of freedom, that is, *I_{d1x/(d1x+d2)}(d1/2, d2/2)*.
+/// 
+/// ```
+/// use comonjo_mathfn::p_f;
+/// 
+/// assert!((p_f(2.5, 3., 10.) - 0.8809604373417218).abs() <= 1e-15);
+/// assert_eq!(p_f(0., 3., 10.), 0.);
+/// ```
+pub fn p_f(x: f64, d1: f64, d2: f64) -> f64 {
+    if is_out_of_domain_of_f(x, d1, d2) { return f64::NAN; }
+    if x <= 0. { return 0.; }
+
+    f_distribution_cdfs(x, d1, d2, false)
+}
+
+/// Return a value of the upper CDF of the F distribution
+/// with *d1* and *d2* degrees of freedom, that is, *I_{d2/(d1x+d2)}(d2/2, d1/2)*.
+/// 
+/// ```
+/// use comonjo_mathfn::q_f;
+/// 
+/// assert!((q_f(100., 2., 4.) / 0.00038446751249519417 - 1.).abs() <= 1e-14);
+/// ```
+pub fn q_f(x: f64, d1: f64, d2: f64) -> f64 {
+    if is_out_of_domain_of_f(x, d1, d2) { return f64::NAN; }
+    if x <= 0. { return 1.; }
+
+    f_distribution_cdfs(x, d1, d2, true)
+}
+
+#[test]
+fn test_the_values_of_p_f_out_of_the_domain(){
+    for (d1, d2) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if !d1.is_nan() && !d2.is_nan() && d1 > 0. && d2 > 0. { continue; }
+        assert!(p_f(1., d1, d2).is_nan(), "p_f(1, {}, {}) = NaN", d1, d2);
+        assert!(q_f(1., d1, d2).is_nan(), "q_f(1, {}, {}) = NaN", d1, d2);
+    }
+    assert!(p_f(f64::NAN, 1., 1.).is_nan());
+    assert!(q_f(f64::NAN, 1., 1.).is_nan());
+}
+
+#[test]
+fn test_the_f_distribution_cdf_properties(){
+    should_the_same_mathfn3(
+        "q_f(x, d1, d2) = 1 - p_f(x, d1, d2)",
+            q_f,
+            |x, d1, d2| 1. - p_f(x, d1, d2))
+        .var0(|v| v.name("x").range(0., 20.).n(20).end())
+        .var1(|v| v.name("d1").range(0.5, 50.).n(20).end())
+        .var2(|v| v.name("d2").range(0.5, 50.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_f(x, d1, d2) = q_f(1/x, d2, d1)",
+            p_f,
+            |x, d1, d2| q_f(1. / x, d2, d1))
+        .var0(|v| v.name("x").range(0., 20.).n(20).end())
+        .var1(|v| v.name("d1").range(0.5, 50.).n(20).end())
+        .var2(|v| v.name("d2").range(0.5, 50.).n(20).end()).assert();
+
+    // F(1, ν) is the distribution of T², where T follows t(ν)
+    should_the_same_mathfn2(
+        "q_f(x², 1, ν) = 2q_t(x, ν)",
+            |x, nu| q_f(x * x, 1., nu),
+            |x, nu| 2. * q_t(x, nu))
+        .filter(|x, _| x >= 0.)
+        .var1(|v| v.name("ν").range(0.5, 50.).end()).assert();
+
+    should_the_same_mathfn2(
+        "p_f(x, d1, ∞) = p_chi2(d1x, d1)",
+            |x, d1| p_f(x, d1, f64::INFINITY),
+            |x, d1| p_chi2(d1 * x, d1))
+        .var0(|v| v.name("x").range(0., 5.).end())
+        .var1(|v| v.name("d1").range(0.5, 50.).end()).assert();
+
+    // F(d1, d2) → χ²(d1)/d1 as d2 → ∞
+    for d2 in [1e6, 1e8, 1e10] {
+        should_the_same_mathfn2(
+            "p_f(x, d1, d2) → p_chi2(d1x, d1) as d2 → ∞",
+                move |x, d1| p_f(x, d1, d2),
+                |x, d1| p_chi2(d1 * x, d1))
+            .var0(|v| v.name("x").range(0., 5.).n(50).end())
+            .var1(|v| v.name("d1").range(0.5, 50.).n(50).end())
+            .epsilon(10. / d2).assert();
+    }
+
+    should_the_same_mathfn2(
+        "p_f(x, ∞, d2) = q_chi2(d2/x, d2)",
+            |x, d2| p_f(x, f64::INFINITY, d2),
+            |x, d2| q_chi2(d2 / x, d2))
+        .filter(|x, _| x > 0.)
+        .var0(|v| v.name("x").range(0., 5.).end())
+        .var1(|v| v.name("d2").range(0.5, 50.).end()).assert();
+
+    // the values by mpmath
+    let values = [
+        ((2.5,  3., 10.), 0.8809604373417218, 0.11903956265827816),
+        ((0.5,  5., 7.),  0.2314156702720843, 0.7685843297279157),
+        ((100., 2., 4.),  0.9996155324875048, 0.00038446751249519417),
+        ((1e-3, 4., 6.),  2.6607496185801794e-06, 0.9999973392503814),
+        ((3.,   1., 1.),  0.6666666666666666, 0.3333333333333333),
+        ((1e5, 10., 3.),  0.999999953113392, 4.688660805144254e-08)];
+    assert_reference_values("p_f", |(x, d1, d2)| p_f(x, d1, d2), &values.map(|(args, p, _)| (args, p)), 1e-13);
+    assert_reference_values("q_f", |(x, d1, d2)| q_f(x, d1, d2), &values.map(|(args, _, q)| (args, q)), 1e-13);
+}
+
+//***** beta distribution *****
+fn is_out_of_domain_of_beta_dist(x: f64, a: f64, b: f64) -> bool {
+    x.is_nan() || a.is_nan() || b.is_nan() || a <= 0. || b <= 0. || a.is_infinite() || b.is_infinite()
+}
+
+/// Return the logarithm of the probability density function of the beta distribution, *log(x^{a-1}(1-x)^{b-1}/Β(a, b))*.
+/// 
+/// ```
+/// use comonjo_mathfn::log_beta_pdf;
+/// 
+/// assert!((log_beta_pdf(0.9, 2000., 5000.) - (-7530.638200430745)).abs() <= 1e-9);
+/// assert_eq!(log_beta_pdf(1.5, 2., 2.), f64::NEG_INFINITY);
+/// ```
+pub fn log_beta_pdf(x: f64, a: f64, b: f64) -> f64 {
+    if is_out_of_domain_of_beta_dist(x, a, b) { return f64::NAN; }
+    if !(0. ..=1.).contains(&x) { return f64::NEG_INFINITY; }
+
+    // the limits at the end points
+    if x == 0. || x == 1. {
+        let c = if x == 0. { a }else{ b };
+        return if c < 1. { f64::INFINITY }else if c > 1. { f64::NEG_INFINITY }else{ -log_beta(a, b) };
+    }
+
+    let (log_x, log_y) = (x.ln(), (-x).ln_1p());
+    log_power_by_beta(a, b, x, 1. - x, log_x, log_y, log_beta(a, b)) - log_x - log_y
+}
+
+/// Return a value of the probability density function of the beta distribution *Beta(a, b)*,
+/// that is, *x^{a-1}(1-x)^{b-1}/Β(a, b)*.
+/// 
+/// ```
+/// use comonjo_mathfn::beta_pdf;
+/// 
+/// assert!((beta_pdf(0.3, 2.5, 4.) - 2.03426725306907).abs() <= 1e-14);
+/// assert_eq!(beta_pdf(0.3, 1., 1.), 1.);
+/// assert_eq!(beta_pdf(1.5, 2., 2.), 0.);
+/// ```
+pub fn beta_pdf(x: f64, a: f64, b: f64) -> f64 {
+    log_beta_pdf(x, a, b).exp()
+}
+
+/// Return a value of the lower CDF of the beta distribution *Beta(a, b)*, that is, *I_x(a, b)*.
+/// (The value is 0 for *x < 0* and 1 for *x > 1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{p_beta, p_beta_dist};
+/// 
+/// assert_eq!(p_beta_dist(0.3, 2.5, 4.), p_beta(2.5, 4., 0.3));
+/// assert_eq!(p_beta_dist(-1., 2.5, 4.), 0.);
+/// assert_eq!(p_beta_dist(2., 2.5, 4.), 1.);
+/// ```
+pub fn p_beta_dist(x: f64, a: f64, b: f64) -> f64 {
+    if is_out_of_domain_of_beta_dist(x, a, b) { return f64::NAN; }
+    if x <= 0. { return 0.; }
+    if x >= 1. { return 1.; }
+
+    p_beta(a, b, x)
+}
+
+/// Return a value of the upper CDF (the survival function) of the beta distribution *Beta(a, b)*,
+/// that is, *1 - I_x(a, b)*.
+/// 
+/// ```
+/// use comonjo_mathfn::{p_beta_dist, q_beta_dist};
+/// 
+/// assert!((q_beta_dist(0.3, 2.5, 4.) - (1. - p_beta_dist(0.3, 2.5, 4.))).abs() <= 1e-15);
+/// ```
+pub fn q_beta_dist(x: f64, a: f64, b: f64) -> f64 {
+    if is_out_of_domain_of_beta_dist(x, a, b) { return f64::NAN; }
+    if x <= 0. { return 1.; }
+    if x >= 1. { return 0.; }
+
+    q_beta(a, b, x)
+}
+
+#[test]
+fn test_the_values_of_beta_dist_out_of_the_domain(){
+    for (a, b) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if a > 0. && a.is_finite() && b > 0. && b.is_finite() { continue; }
+        assert!(beta_pdf(0.5, a, b).is_nan(), "beta_pdf(0.5, {}, {}) = NaN", a, b);
+        assert!(log_beta_pdf(0.5, a, b).is_nan(), "log_beta_pdf(0.5, {}, {}) = NaN", a, b);
+        assert!(p_beta_dist(0.5, a, b).is_nan(), "p_beta_dist(0.5, {}, {}) = NaN", a, b);
+        assert!(q_beta_dist(0.5, a, b).is_nan(), "q_beta_dist(0.5, {}, {}) = NaN", a, b);
+    }
+    assert!(beta_pdf(f64::NAN, 1., 1.).is_nan());
+    assert!(p_beta_dist(f64::NAN, 1., 1.).is_nan());
+    assert!(q_beta_dist(f64::NAN, 1., 1.).is_nan());
+}
+
+#[test]
+fn test_the_beta_distribution_properties(){
+    should_the_same_mathfn3(
+        "q_beta_dist(x, a, b) = 1 - p_beta_dist(x, a, b)",
+            q_beta_dist,
+            |x, a, b| 1. - p_beta_dist(x, a, b))
+        .var0(|v| v.name("x").range(-0.5, 1.5).n(20).end())
+        .var1(|v| v.name("a").range(0.1, 50.).n(20).end())
+        .var2(|v| v.name("b").range(0.1, 50.).n(20).end()).assert();
+
+    should_the_same_mathfn2(
+        "beta_pdf(x, a, 1) = ax^{a-1}",
+            |x, a| beta_pdf(x, a, 1.),
+            |x, a| a * x.powf(a - 1.))
+        .filter(|x, _| 0. < x && x < 1.)
+        .var0(|v| v.name("x").range(0., 1.).end())
+        .var1(|v| v.name("a").range(0.1, 10.).end()).assert();
+
+    // the density is the derivative of the CDF: (I_{x+h} - I_{x-h})/(2h)
+    should_the_same_mathfn3(
+        "beta_pdf(x, a, b) = d/dx p_beta_dist(x, a, b)",
+            beta_pdf,
+            |x, a, b| {
+                let h = 1e-5;
+                (p_beta_dist(x + h, a, b) - p_beta_dist(x - h, a, b)) / (2. * h)
+            })
+        .var0(|v| v.name("x").range(0.05, 0.95).n(20).end())
+        .var1(|v| v.name("a").range(1., 10.).n(20).end())
+        .var2(|v| v.name("b").range(1., 10.).n(20).end())
+        .epsilon(1e-6).assert();
+
+    // the values by mpmath
+    assert_reference_values("beta_pdf", |(x, a, b)| beta_pdf(x, a, b), &[
+        ((0.3,   2.5, 4.),  2.03426725306907),
+        ((0.01,  0.5, 0.5), 3.199134725855654),
+        ((0.7,   50., 20.), 6.905820833231028),
+        ((0.999, 3.,  0.2), 66.18124050207095)], 1e-13);
+
+    // the values by mpmath (the densities of large shapes underflow away from the mode)
+    assert_reference_values("log_beta_pdf", |(x, a, b)| log_beta_pdf(x, a, b), &[
+        ((0.3,    2.5,   4.),    0.7101356818510662),
+        ((0.9,    2000., 5000.), -7530.638200430745),
+        ((0.2857, 2000., 5000.), 4.3024918561813115),
+        ((0.5,    1e10,  1e10),  11.633707702592973),
+        ((1e-5,   0.5,   3.),    5.691904211247542)], EPS);
+
+    assert_eq!(beta_pdf(0., 0.5, 2.), f64::INFINITY);
+    assert_eq!(beta_pdf(0., 2., 2.), 0.);
+    assert_eq!(beta_pdf(1., 1., 3.), 0.);
+    assert!((beta_pdf(0., 1., 3.) - 3.).abs() <= 1e-14);
+}
@@ -0,0 +1,486 @@
+// The tests refer to
+// <a href="https://en.wikipedia.org/wiki/Beta_distribution">Beta distribution</a>
+// <a href="https://en.wikipedia.org/wiki/Student%27s_t-distribution">Student's t-distribution</a>
+// <a href="https://en.wikipedia.org/wiki/F-distribution">F-distribution</a>
+
+use crate::beta_fn::log_beta;
+use crate::ibeta_fn::{p_beta_normalizable_xy, upper_tail_of_t, p_f, q_f};
+use crate::igamma_inv_fn::{rough_normal_quantile, find_root_in_bracket, q_normal_inv, p_chi2_inv, q_chi2_inv};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Return a positive root of the increasing function *g*,
+/// which is bracketed by doubling or halving the initial guess *x0 > 0*.
+fn find_root_from_guess<F>(g: F, x0: f64) -> f64
+    where F: Fn(f64) -> f64
+{
+    let mut lo = x0;
+    let mut hi = x0;
+    if g(lo) < 0. {
+        while g(hi) < 0. {
+            lo = hi; hi *= 2.;
+            if hi > f64::MAX / 2. { return f64::INFINITY; }
+        }
+    }else{
+        while g(lo) > 0. {
+            hi = lo; lo *= 0.5;
+            if lo < f64::MIN_POSITIVE { return 0.; }
+        }
+    }
+
+    find_root_in_bracket(g, lo, hi)
+}
+
+//***** inverse incomplete beta function *****
+/// Return an initial guess of *x* such that *I_x(a, b) = p* (and *1 - I_x(a, b) = q*).
+fn initial_guess_of_p_beta_inv(a: f64, b: f64, p: f64, q: f64, log_beta_ab: f64) -> f64 {
+    // the tails: I_x(a, b) ~ x^a/(aΒ(a, b)) and 1 - I_x(a, b) ~ (1-x)^b/(bΒ(a, b))
+    let mean = a / (a + b);
+    let x_lower = ((p.ln() + a.ln() + log_beta_ab) / a).exp();
+    if x_lower < 0.2 * mean { return x_lower; }
+    let y_upper = ((q.ln() + b.ln() + log_beta_ab) / b).exp();
+    if y_upper < 0.2 * (1. - mean) { return 1. - y_upper; }
+
+    if a >= 1. && b >= 1. {
+        // the normal approximation
+        let z = -rough_normal_quantile(p, q);
+        let l = (z * z - 3.) / 6.;
+        let h = 2. / (1. / (2. * a - 1.) + 1. / (2. * b - 1.));
+        let w = z * (h + l).sqrt() / h - (1. / (2. * b - 1.) - 1. / (2. * a - 1.)) * (l + 5. / 6. - 2. / (3. * h));
+        a / (a + b * (2. * w).exp())
+    }else{
+        // the asymptotes I_x(a, b) ~ x^a/(aΒ(a, b)) near 0 and 1 - (1-x)^b/(bΒ(a, b)) near 1,
+        // where Β(a, b) is roughly approximated by t + u
+        let t = (a * (a / (a + b)).ln()).exp() / a;
+        let u = (b * (b / (a + b)).ln()).exp() / b;
+        let w = t + u;
+        if p < t / w { (a * w * p).powf(1. / a) }else{ 1. - (b * w * q).powf(1. / b) }
+    }
+}
+
+/// Solve *log I_x(a, b) = log p* if *p ≤ 1/2*, otherwise *log(1 - I_x(a, b)) = log q*, for *x*
+/// by the Halley method, where the steps out of the bracket of the root are replaced by the bisection.
+fn solve_p_beta(a: f64, b: f64, p: f64, q: f64) -> f64 {
+    let log_beta_ab = log_beta(a, b);
+    let upper = p > 0.5;
+    let log_target = if upper { q.ln() }else{ p.ln() };
+    let mut x = initial_guess_of_p_beta_inv(a, b, p, q, log_beta_ab);
+    // the root underflows or rounds to 1 (the asymptotes of the tails are accurate enough there)
+    if x < f64::MIN_POSITIVE || x == 1. { return x; }
+
+    let (mut lo, mut hi) = (0., 1.);
+    for _ in 0..MAX_ITERATIONS {
+        // f(x) = log I_x(a, b) - log p, f'(x) = x^{a-1}(1-x)^{b-1}/(Β(a, b)I_x(a, b))
+        // and f''/f' = (a-1)/x - (b-1)/(1-x) - f'
+        // (the sign of f' is reversed for 1 - I_x(a, b) = I_{1-x}(b, a))
+        let i = if upper {
+            p_beta_normalizable_xy(b, a, 1. - x, x, log_beta_ab)
+        }else{
+            p_beta_normalizable_xy(a, b, x, 1. - x, log_beta_ab)
+        };
+        let f = i.ln() - log_target;
+        if f == 0. { return x; }
+        if (f < 0.) != upper { lo = x; }else{ hi = x; }
+
+        let mut df = ((a - 1.) * x.ln() + (b - 1.) * (-x).ln_1p() - log_beta_ab).exp() / i;
+        if upper { df = -df; }
+        let u = f / df;
+        let mut next = x - u / (1. - 0.5 * (u * ((a - 1.) / x - (b - 1.) / (1. - x) - df)).min(1.));
+        if !(lo < next && next < hi) { next = 0.5 * (lo + hi); }
+
+        let prev = x;
+        x = next;
+        if (x - prev).abs() <= f64::EPSILON * x { break; }
+    }
+
+    x
+}
+
+/// Return *x* such that *I_x(a, b) = p*, the inverse of the regularized incomplete beta function,
+/// that is, the quantile function of the beta distribution *Beta(a, b)*.
+/// (The arguments *a* and *b* must be positive and *0 ≤ p ≤ 1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{p_beta, p_beta_inv};
+/// 
+/// // the 95% equal-tailed credible interval for Beta(3, 7)
+/// assert!((p_beta_inv(0.025, 3., 7.) - 0.07485463141969181).abs() <= 1e-15);
+/// assert!((p_beta_inv(0.975, 3., 7.) - 0.6000935737163121).abs() <= 1e-15);
+/// 
+/// let x = p_beta_inv(0.1, 0.05, 2.);
+/// assert!((p_beta(0.05, 2., x) / 0.1 - 1.).abs() <= 1e-14);
+/// ```
+pub fn p_beta_inv(p: f64, a: f64, b: f64) -> f64 {
+    if p.is_nan() || a.is_nan() || b.is_nan() || a <= 0. || b <= 0. || a.is_infinite() || b.is_infinite() { return f64::NAN; }
+    if !(0. ..=1.).contains(&p) { return f64::NAN; }
+    if p == 0. { return 0.; }
+    if p == 1. { return 1.; }
+
+    solve_p_beta(a, b, p, 1. - p)
+}
+
+/// Return *x* such that *1 - I_x(a, b) = q*, the inverse of the complement of the regularized incomplete beta function.
+/// (The arguments *a* and *b* must be positive and *0 ≤ q ≤ 1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{q_beta, q_beta_inv};
+/// 
+/// assert!((q_beta_inv(0.025, 3., 7.) - 0.6000935737163121).abs() <= 1e-15);
+/// 
+/// let x = q_beta_inv(1e-10, 2.5, 4.);
+/// assert!((q_beta(2.5, 4., x) / 1e-10 - 1.).abs() <= 1e-12);
+/// ```
+pub fn q_beta_inv(q: f64, a: f64, b: f64) -> f64 {
+    if q.is_nan() || a.is_nan() || b.is_nan() || a <= 0. || b <= 0. || a.is_infinite() || b.is_infinite() { return f64::NAN; }
+    if !(0. ..=1.).contains(&q) { return f64::NAN; }
+    if q == 0. { return 1.; }
+    if q == 1. { return 0.; }
+
+    solve_p_beta(a, b, 1. - q, q)
+}
+
+//***** inverse Student's t distribution *****
+/// Return *x ≥ 0* such that the upper tail probability *P(T > x)* equals *q ≤ 1/2*.
+///
+/// The root of *log P(T > x) = log q* is searched from
+/// the Cornish–Fisher approximation *z + (z³ + z)/(4ν)* (*z = Q⁻¹_normal(q)*).
+fn solve_upper_tail_of_t(q: f64, nu: f64) -> f64 {
+    if nu == 1. {
+        // Cauchy distribution: 1/tan(πq)
+        return 1. / (std::f64::consts::PI * q).tan();
+    }
+    if nu == 2. {
+        // (1 - 2q)/√(2q(1-q))
+        return (1. - 2. * q) / (2. * q * (1. - q)).sqrt();
+    }
+    if nu.is_infinite() { return q_normal_inv(q); }
+
+    let log_q = q.ln();
+    let z = q_normal_inv(q);
+    find_root_from_guess(|x| log_q - upper_tail_of_t(x, nu).ln(), (z + (z * z * z + z) / (4. * nu)).max(1.))
+}
+
+/// Return a value of the quantile function (the inverse of the lower CDF) of Student's t distribution
+/// with *ν* degrees of freedom. (The argument *p* must be in *[0, 1]*.)
+///
+/// The closed forms are used for *ν = 1* (Cauchy distribution) and *ν = 2*.
+///
+/// ```
+/// use comonjo_mathfn::{p_t, p_t_inv};
+///
+/// assert!((p_t_inv(0.975, 10.) - 2.2281388519862744).abs() <= 1e-14);
+/// assert!((p_t_inv(0.75, 1.) - 1.).abs() <= 1e-15);
+/// assert_eq!(p_t_inv(0.5, 3.), 0.);
+///
+/// let x = p_t_inv(0.1, 4.5);
+/// assert!((p_t(x, 4.5) - 0.1).abs() <= 1e-15);
+/// ```
+pub fn p_t_inv(p: f64, nu: f64) -> f64 {
+    if p.is_nan() || nu.is_nan() || nu <= 0. || !(0. ..=1.).contains(&p) { return f64::NAN; }
+    if p == 0. { return f64::NEG_INFINITY; }
+    if p == 1. { return f64::INFINITY; }
+    if p == 0.5 { return 0.; }
+
+    if p < 0.5 {
+        -solve_upper_tail_of_t(p, nu)
+    }else{
+        solve_upper_tail_of_t(1. - p, nu)
+    }
+}
+
+/// Return a value of the quantile function of the upper CDF of Student's t distribution
+/// with *ν* degrees of freedom, that is, *x* such that `q_t(x, ν)` *= q*. (The argument must be in *[0, 1]*.)
+///
+/// ```
+/// use comonjo_mathfn::q_t_inv;
+///
+/// // the critical value of the two-sided test at the significance level 5% for 10 degrees of freedom
+/// assert!((q_t_inv(0.025, 10.) - 2.2281388519862744).abs() <= 1e-14);
+/// ```
+pub fn q_t_inv(q: f64, nu: f64) -> f64 {
+    -p_t_inv(q, nu)
+}
+
+//***** inverse F distribution *****
+/// Return *x* such that the lower CDF of the F distribution is *p* (and the upper CDF is *q = 1 - p*).
+fn solve_f_distribution(p: f64, q: f64, d1: f64, d2: f64) -> f64 {
+    if d1.is_nan() || d2.is_nan() || d1 <= 0. || d2 <= 0. { return f64::NAN; }
+    if p == 0. { return 0.; }
+    if q == 0. { return f64::INFINITY; }
+
+    // F → χ²(d1)/d1 as d2 → ∞, and F → d2/χ²(d2) as d1 → ∞
+    if d1.is_infinite() && d2.is_infinite() { return 1.; }
+    if d2.is_infinite() {
+        return if p <= 0.5 { p_chi2_inv(p, d1) }else{ q_chi2_inv(q, d1) } / d1;
+    }
+    if d1.is_infinite() {
+        return d2 / if p <= 0.5 { q_chi2_inv(p, d2) }else{ p_chi2_inv(q, d2) };
+    }
+
+    // the logarithm of the smaller tail is solved
+    if p <= 0.5 {
+        let log_p = p.ln();
+        find_root_from_guess(|x| p_f(x, d1, d2).ln() - log_p, 1.)
+    }else{
+        let log_q = q.ln();
+        find_root_from_guess(|x| log_q - q_f(x, d1, d2).ln(), 1.)
+    }
+}
+
+/// Return a value of the quantile function (the inverse of the lower CDF) of the F distribution
+/// with *d1* and *d2* degrees of freedom. (The argument *p* must be in *[0, 1]*.)
+///
+/// ```
+/// use comonjo_mathfn::{p_f, p_f_inv};
+///
+/// // the critical value of ANOVA at the significance level 5% for (3, 10) degrees of freedom
+/// assert!((p_f_inv(0.95, 3., 10.) - 3.708264819046843).abs() <= 1e-14);
+///
+/// let x = p_f_inv(0.05, 5., 7.);
+/// assert!((p_f(x, 5., 7.) - 0.05).abs() <= 1e-15);
+/// ```
+pub fn p_f_inv(p: f64, d1: f64, d2: f64) -> f64 {
+    if p.is_nan() || !(0. ..=1.).contains(&p) { return f64::NAN; }
+    solve_f_distribution(p, 1. - p, d1, d2)
+}
+
+/// Return a value of the quantile function of the upper CDF of the F distribution
+/// with *d1* and *d2* degrees of freedom, that is, *x* such that `q_f(x, d1, d2)` *= q*.
+/// (The argument *q* must be in *[0, 1]*.)
+///
+/// ```
+/// use comonjo_mathfn::q_f_inv;
+///
+/// assert!((q_f_inv(0.01, 1., 20.) - 8.095958064085695).abs() <= 1e-14);
+/// ```
+pub fn q_f_inv(q: f64, d1: f64, d2: f64) -> f64 {
+    if q.is_nan() || !(0. ..=1.).contains(&q) { return f64::NAN; }
+    solve_f_distribution(1. - q, q, d1, d2)
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::ibeta_fn::{p_beta, q_beta, p_t, q_t};
+#[cfg(test)]
+use crate::igamma_inv_fn::p_normal_inv;
+
+#[test]
+fn test_the_values_of_p_t_inv_out_of_the_domain(){
+    for (p, nu) in non_finite_args2_with([-0.5, 0.5, 1.5], [-1., 0.]){
+        if (0. ..=1.).contains(&p) && !nu.is_nan() && nu > 0. { continue; }
+        assert!(p_t_inv(p, nu).is_nan(), "p_t_inv({}, {}) = NaN", p, nu);
+    }
+}
+
+#[test]
+fn test_the_t_distribution_quantile_function_properties(){
+    should_the_same_mathfn2(
+        "p_t(p_t_inv(p, ν), ν) = p",
+            |p, nu| p_t(p_t_inv(p, nu), nu),
+            |p, _| p)
+        .filter(|p, _| 0. < p && p < 1.)
+        .var0(|v| v.name("p").range(0., 1.).end())
+        .var1(|v| v.name("ν").range(0.5, 50.).end()).assert();
+
+    should_the_same_mathfn2(
+        "log q_t(q_t_inv(10^e, ν), ν) = e log 10",
+            |e, nu| q_t(q_t_inv(10f64.powf(e), nu), nu).ln(),
+            |e, _| e * std::f64::consts::LN_10)
+        .var0(|v| v.name("e").range(-300., -1.).end())
+        .var1(|v| v.name("ν").range(3., 100.).end()).assert();
+
+    should_the_same_mathfn2(
+        "log p_t(p_t_inv(10^e, ν), ν) = e log 10",
+            |e, nu| p_t(p_t_inv(10f64.powf(e), nu), nu).ln(),
+            |e, _| e * std::f64::consts::LN_10)
+        .var0(|v| v.name("e").range(-300., -1.).end())
+        .var1(|v| v.name("ν").range(1.55, 2.95).end()).assert();
+
+    should_the_same_mathfn2(
+        "q_t_inv(q, ν) = -p_t_inv(q, ν)",
+            q_t_inv,
+            |q, nu| -p_t_inv(q, nu))
+        .var1(|v| v.name("ν").range(0.5, 50.).end()).assert();
+
+    should_the_same_mathfn(
+        "p_t_inv(p, 1) = tan(π(p - 1/2))",
+            |p| p_t_inv(p, 1.),
+            |p| (std::f64::consts::PI * (p - 0.5)).tan())
+        .filter(|p| 0.01 < p && p < 0.99).assert();
+
+    should_the_same_mathfn(
+        "p_t_inv(p, 2) = (2p - 1)/√(2p(1-p))",
+            |p| p_t_inv(p, 2.),
+            |p| (2. * p - 1.) / (2. * p * (1. - p)).sqrt())
+        .filter(|p| 0.01 < p && p < 0.99).assert();
+
+    should_the_same_mathfn(
+        "p_t_inv(p, ∞) = p_normal_inv(p)",
+            |p| p_t_inv(p, f64::INFINITY),
+            p_normal_inv).assert();
+
+    // the values by mpmath
+    assert_reference_values("p_t_inv", |(p, nu)| p_t_inv(p, nu), &[
+        ((0.975, 10.),  2.2281388519862744),
+        ((0.95,  3.),   2.353363434801823),
+        ((0.99,  4.5),  3.5270508906260916),
+        ((0.9,   30.),  1.3104150253913958),
+        ((1e-10, 5.),   -156.8255927088943),
+        ((0.3,   0.5),  -1.0095258786071661),
+        ((0.999, 1e4),  3.0910475160306117),
+        ((0.01,  0.2),  -75082859.34583001),
+        ((1e-300, 1.5), -5.219469427344636e199),
+        ((1e-250, 4.5), -5.194887647302485e55),
+        ((1e-100, 0.5), -1.02849115631634e199)], 1e-13);
+}
+
+#[test]
+fn test_the_values_of_p_f_inv_out_of_the_domain(){
+    for (d1, d2) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if !d1.is_nan() && !d2.is_nan() && d1 > 0. && d2 > 0. { continue; }
+        assert!(p_f_inv(0.5, d1, d2).is_nan(), "p_f_inv(0.5, {}, {}) = NaN", d1, d2);
+        assert!(q_f_inv(0.5, d1, d2).is_nan(), "q_f_inv(0.5, {}, {}) = NaN", d1, d2);
+    }
+    for p in [f64::NAN, -0.5, 1.5] {
+        assert!(p_f_inv(p, 1., 1.).is_nan(), "p_f_inv({}, 1, 1) = NaN", p);
+        assert!(q_f_inv(p, 1., 1.).is_nan(), "q_f_inv({}, 1, 1) = NaN", p);
+    }
+}
+
+#[test]
+fn test_the_f_distribution_quantile_function_properties(){
+    should_the_same_mathfn3(
+        "p_f(p_f_inv(p, d1, d2), d1, d2) = p",
+            |p, d1, d2| p_f(p_f_inv(p, d1, d2), d1, d2),
+            |p, _, _| p)
+        .filter(|p, _, _| 0. < p && p < 1.)
+        .var0(|v| v.name("p").range(0., 1.).n(20).end())
+        .var1(|v| v.name("d1").range(0.5, 50.).n(20).end())
+        .var2(|v| v.name("d2").range(0.5, 50.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "log q_f(q_f_inv(10^e, d1, d2), d1, d2) = e log 10",
+            |e, d1, d2| q_f(q_f_inv(10f64.powf(e), d1, d2), d1, d2).ln(),
+            |e, _, _| e * std::f64::consts::LN_10)
+        // the quantile overflows for small degrees of freedom
+        .filter(|e, d1, d2| q_f_inv(10f64.powf(e), d1, d2).is_finite())
+        .var0(|v| v.name("e").range(-300., -1.).n(20).end())
+        .var1(|v| v.name("d1").range(1., 50.).n(20).end())
+        .var2(|v| v.name("d2").range(1., 50.).n(20).end()).assert();
+
+    // F(1, ν) is the distribution of T², where T follows t(ν)
+    should_the_same_mathfn2(
+        "q_f_inv(q, 1, ν) = q_t_inv(q/2, ν)²",
+            |q, nu| q_f_inv(q, 1., nu),
+            |q, nu| q_t_inv(0.5 * q, nu).powi(2))
+        .filter(|q, _| 0. < q && q < 1.)
+        .var0(|v| v.name("q").range(0., 1.).end())
+        .var1(|v| v.name("ν").range(0.5, 50.).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "p_f_inv(p, d1, ∞) = p_chi2_inv(p, d1)/d1",
+            |p, d1| p_f_inv(p, d1, f64::INFINITY),
+            |p, d1| p_chi2_inv(p, d1) / d1)
+        .filter(|p, _| p <= 0.5)
+        .var0(|v| v.name("p").range(0., 1.).end())
+        .var1(|v| v.name("d1").range(0.5, 50.).end()).assert();
+
+    // the values by mpmath
+    assert_reference_values("p_f_inv", |(p, d1, d2)| p_f_inv(p, d1, d2), &[
+        ((0.95, 3., 10.), 3.708264819046843),
+        ((0.99, 1., 20.), 8.095958064085695),
+        ((0.05, 5., 7.),  0.20509153283389542),
+        ((0.5,  2., 2.),  1.),
+        ((1e-8, 4., 6.),  6.124141056230498e-05)], 1e-13);
+}
+
+#[test]
+fn test_the_values_of_p_beta_inv_out_of_the_domain(){
+    for (a, b) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if a > 0. && a.is_finite() && b > 0. && b.is_finite() { continue; }
+        assert!(p_beta_inv(0.5, a, b).is_nan(), "p_beta_inv(0.5, {}, {}) = NaN", a, b);
+        assert!(q_beta_inv(0.5, a, b).is_nan(), "q_beta_inv(0.5, {}, {}) = NaN", a, b);
+    }
+    for p in [f64::NAN, -0.5, 1.5] {
+        assert!(p_beta_inv(p, 1., 1.).is_nan(), "p_beta_inv({}, 1, 1) = NaN", p);
+        assert!(q_beta_inv(p, 1., 1.).is_nan(), "q_beta_inv({}, 1, 1) = NaN", p);
+    }
+}
+
+#[test]
+fn test_the_inverse_regularized_incomplete_beta_function_properties(){
+    // (I_x(a, b) is ill-conditioned near x = 1 for b < 1)
+    should_the_same_mathfn3(
+        "I_{p_beta_inv(p, a, b)}(a, b) = p",
+            |p, a, b| p_beta(a, b, p_beta_inv(p, a, b)),
+            |p, _, _| p)
+        .filter(|p, a, b| p_beta_inv(p, a, b) < 0.9)
+        .var0(|v| v.name("p").range(0., 1.).n(20).end())
+        .var1(|v| v.name("a").range(0.1, 50.).n(20).end())
+        .var2(|v| v.name("b").range(0.1, 50.).n(20).end()).assert();
+
+    // tiny a or b
+    should_the_same_mathfn3(
+        "I_{p_beta_inv(p, a, b)}(a, b) = p for tiny a or b",
+            |p, a, b| p_beta(a, b, p_beta_inv(p, a, b)),
+            |p, _, _| p)
+        .filter(|p, a, b| p_beta_inv(p, a, b) < 0.9)
+        .var0(|v| v.name("p").range(0.01, 0.99).n(20).end())
+        .var1(|v| v.name("a").range(0.02, 0.2).n(20).end())
+        .var2(|v| v.name("b").range(0.5, 20.).n(20).end()).assert();
+
+    should_the_same_mathfn2(
+        "log I_{p_beta_inv(10^e, a, 2.5)}(a, 2.5) = e log 10",
+            |e, a| p_beta(a, 2.5, p_beta_inv(10f64.powf(e), a, 2.5)).ln(),
+            |e, _| e * std::f64::consts::LN_10)
+        // the root underflows (or is subnormal) for small a
+        .filter(|e, a| p_beta_inv(10f64.powf(e), a, 2.5) >= f64::MIN_POSITIVE)
+        .var0(|v| v.name("e").range(-300., -1.).end())
+        .var1(|v| v.name("a").range(0.5, 50.).end()).assert();
+
+    // (1 - x close to 0 is ill-conditioned since x is rounded)
+    should_the_same_mathfn2(
+        "log(1 - I_{q_beta_inv(10^e, a, 2.5)}(a, 2.5)) = e log 10",
+            |e, a| q_beta(a, 2.5, q_beta_inv(10f64.powf(e), a, 2.5)).ln(),
+            |e, _| e * std::f64::consts::LN_10)
+        .var0(|v| v.name("e").range(-10., -1.).end())
+        .var1(|v| v.name("a").range(0.5, 50.).end())
+        .epsilon(1e-10).assert();
+
+    should_the_same_mathfn2(
+        "p_beta_inv(p, a, 1) = p^{1/a}",
+            |p, a| p_beta_inv(p, a, 1.),
+            |p, a| p.powf(1. / a))
+        .var0(|v| v.name("p").range(0., 1.).end())
+        .var1(|v| v.name("a").range(0.5, 10.).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_beta_inv(p, a, b) = 1 - p_beta_inv(1 - p, b, a)",
+            p_beta_inv,
+            |p, a, b| 1. - p_beta_inv(1. - p, b, a))
+        .var0(|v| v.name("p").range(0., 1.).n(20).end())
+        .var1(|v| v.name("a").range(0.5, 50.).n(20).end())
+        .var2(|v| v.name("b").range(0.5, 50.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_beta_inv(q, a, b) = p_beta_inv(1 - q, a, b)",
+            q_beta_inv,
+            |q, a, b| p_beta_inv(1. - q, a, b))
+        .var0(|v| v.name("q").range(0., 1.).n(20).end())
+        .var1(|v| v.name("a").range(0.5, 50.).n(20).end())
+        .var2(|v| v.name("b").range(0.5, 50.).n(20).end()).assert();
+
+    // the values by mpmath
+    assert_reference_values("p_beta_inv", |(p, a, b)| p_beta_inv(p, a, b), &[
+        ((0.025, 3.,   7.),   0.07485463141969181),
+        ((0.975, 3.,   7.),   0.6000935737163121),
+        ((0.5,   0.5,  0.5),  0.5),
+        ((1e-10, 2.5,  4.),   3.437288591518447e-05),
+        ((0.3,   50.,  80.),  0.3617865137675907),
+        ((0.1,   0.05, 2.),   3.7688948287300206e-21),
+        ((0.001, 0.3,  0.2),  1.6638959426459832e-09),
+        ((0.2,   3.,   0.02), 0.9999967757788836),
+        ((0.99,  5.,   1e-3), 1.)], 1e-13);
+}
@@ -1,16 +1,20 @@
 // The tests refer to
 // <a href="https://en.m.wikipedia.org/wiki/Incomplete_gamma_function">Incomplete gamma function</a>
 
-use crate::gamma_fn::log_gamma;
+use crate::gamma_fn::{N, log_gamma, stirling_series};
 
 /** (log π)/2 */
 const LOG_PI_BY2: f64 = 0.5723649429247001;
 
+/** log of the minimum positive normal value of f64 */
+const LOG_MIN_POSITIVE: f64 = -708.3964185322641;
+
 /// Return a value of the the regularized incomplete gamma function.
 /// The normalization factor can be manually specified by the last argument.
 /// 
 /// Ref: 『改訂新版 Cによる標準アルゴリズム事典』不完全ガンマ関数 (incomplete gamma function) igamma.c
-fn p_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
+pub(crate) fn p_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
+    if is_in_the_range_of_temme(s, x) { return q_gamma_temme(s, x, true) * (log_gamma(s) - log_gamma_s).exp(); }
     if x >= 1. + s { return 1. - q_gamma_normalizable(s, x, log_gamma_s); }
     if x == 0. { return 0.; }
 
@@ -30,11 +34,60 @@ fn p_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
 
     // f64::NAN;
 
-    let a0 = x.powf(s) * (-x - log_gamma_s).exp() / s;
-    hyper_geometric_series(a0, |k| x / (s + k), 1., 1000.)
+    let a0 = power_exp_by_gamma(s, x, log_gamma_s) / s;
+    hyper_geometric_series(a0, |k| x / (s + k), 1., max_iterations(s))
+}
+
+/// Return *x^s e^{-x}/Γ(s)*, where *log Γ(s)* is given by the last argument.
+/// 
+/// The log form is used if *s* is large or *e^{-x}* underflows.
+pub(crate) fn power_exp_by_gamma(s: f64, x: f64, log_gamma_s: f64) -> f64 {
+    if x > 0. && s.is_finite() && (s >= N || -x < LOG_MIN_POSITIVE) {
+        log_power_exp_by_gamma(s, x, log_gamma_s).exp()
+    }else{
+        x.powf(s) * (-x - log_gamma_s).exp()
+    }
+}
+
+/// Return *log(x^s e^{-x}/Γ(s))* for positive *x*, where *log Γ(s)* is given by the last argument.
+/// (For *s ≥ N*, *log Γ(s)* is expanded by the Stirling series instead, and `log_gamma_s` is not used.)
+pub(crate) fn log_power_exp_by_gamma(s: f64, x: f64, log_gamma_s: f64) -> f64 {
+    if s >= N && s.is_finite() {
+        let d = x - s;
+        // s log(x/s) - d
+        let main = if d.abs() < 0.5 * s { s * log1pmx(d / s) }else{ s * (x / s).ln() - d };
+        main - stirling_series(s) + 0.5 * (s / std::f64::consts::TAU).ln()
+    }else{
+        s * x.ln() - x - log_gamma_s
+    }
+}
+
+/// Return *log(1+t) - t* without the cancellation for *|t| ≤ 1/2*.
+/// 
+/// By *log(1+t) = 2 atanh(u)* (*u = t/(2+t)*), the value is *-t²/(2+t) + 2(u³/3 + u⁵/5 + ...)*.
+pub(crate) fn log1pmx(t: f64) -> f64 {
+    let u = t / (2. + t);
+    let u2 = u * u;
+    let mut term = 2. * u * u2;
+    let mut sum = 0.;
+    let mut k = 3.;
+    while k < 100. {
+        let prev = sum;
+        sum += term / k;
+        if sum == prev { break; }
+        term *= u2;
+        k += 2.;
+    }
+    -t * t / (2. + t) + sum
 }
 
-fn hyper_geometric_series<F>(mut a: f64, mut nth_additional_factor: F, mut n0: f64, n_max: f64) -> f64
+/// Return the maximum number of the terms of the series of *P(s, x)* and *Q(s, x)*.
+/// The number of the terms needed near *x = s* grows as *√s*.
+pub(crate) fn max_iterations(s: f64) -> f64 {
+    (1000. + 10. * s.abs().sqrt()).min(1e5)
+}
+
+pub(crate) fn hyper_geometric_series<F>(mut a: f64, mut nth_additional_factor: F, mut n0: f64, n_max: f64) -> f64
     where F: FnMut(f64) -> f64
 {
     let mut term = a;
@@ -53,17 +106,64 @@ fn hyper_geometric_series<F>(mut a: f64, mut nth_additional_factor: F, mut n0: f
 /// The normalization factor can be manually specified by the last argument.
 /// 
 /// Ref: 『改訂新版 Cによる標準アルゴリズム事典』不完全ガンマ関数 (incomplete gamma function) igamma.c
-fn q_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
+pub(crate) fn q_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
+    if is_in_the_range_of_temme(s, x) { return q_gamma_temme(s, x, false) * (log_gamma(s) - log_gamma_s).exp(); }
     if x < 1. + s { return 1. - p_gamma_normalizable(s, x, log_gamma_s); }
 
-    let mut w = x.powf(s) * (-x - log_gamma_s).exp();
+    power_exp_by_gamma(s, x, log_gamma_s) * q_gamma_scaled(s, x)
     // let mut w = (s * x.ln() - x - log_gamma_s).exp();
        // The above line doesn't work when s is a non-positive integer and x is negative.
-    
+}
+
+/// Return *Q(s, x)Γ(s)/(x^s e^{-x})* (for *x ≥ 1 + s*).
+/// 
+/// For large *s*, the terms of the Laguerre polynomial expansion alternate and cancel out near *x = s*,
+/// so the continued fraction is used instead.
+pub(crate) fn q_gamma_scaled(s: f64, x: f64) -> f64 {
+    if s >= 100. && x > 0. {
+        q_gamma_continued_fraction(s, x)
+    }else{
+        q_gamma_laguerre_series(s, x)
+    }
+}
+
+/// Return *Q(s, x)Γ(s)/(x^s e^{-x}) = 1/(x+1-s- 1(1-s)/(x+3-s- 2(2-s)/(x+5-s- ...)))*
+/// by the modified Lentz method.
+fn q_gamma_continued_fraction(s: f64, x: f64) -> f64 {
+    const FPMIN: f64 = 1e-300;
+    fn not_too_small(t: f64) -> f64 {
+        if t.abs() < FPMIN { FPMIN }else{ t }
+    }
+
+    let mut b = x + 1. - s;
+    let mut c = 1. / FPMIN;
+    let mut d = 1. / not_too_small(b);
+    let mut result = d;
+    let mut i = 1.;
+    let i_max = max_iterations(s);
+    while i < i_max {
+        let an = -i * (i - s);
+        b += 2.;
+        d = 1. / not_too_small(an * d + b);
+        c = not_too_small(b + an / c);
+        let delta = d * c;
+        result *= delta;
+
+        if (delta - 1.).abs() <= f64::EPSILON { return result; }
+        i += 1.;
+    }
+
+    f64::NAN
+}
+
+/// Return *Q(s, x)Γ(s)/(x^s e^{-x})* by the Laguerre polynomial expansion (valid for *x ≥ 1 + s*).
+fn q_gamma_laguerre_series(s: f64, x: f64) -> f64 {
+    let mut w = 1.;
     let mut la = 1.; let mut lb = 1. + x - s;
     let mut result = w / lb;
     let mut k = 2.;
-    while k < 1000. {
+    let k_max = max_iterations(s);
+    while k < k_max {
         let mut temp = ((k-1.-s)*(lb-la) + (k+x)*lb)/k;
         la = lb; lb = temp;
         w *= (k-1.-s)/k;
@@ -73,7 +173,7 @@ fn q_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
         if result == prev { return result; }
         k += 1.;
     }
-    return f64::NAN;
+    f64::NAN
 
     // let la = 1.; let lb = 1. + x - s;
     // let mut laguerre2 = std::iter::successors(Some((1., la, lb)), |&(k, la, lb)|{
@@ -97,6 +197,61 @@ fn q_gamma_normalizable(s: f64, x: f64, log_gamma_s: f64) -> f64 {
     // return f64::NAN;
 }
 
+/// The minimum *s* for which the uniform asymptotic expansion is used near *x = s*.
+const TEMME_MIN: f64 = 1e4;
+
+/// The maximum *|x/s - 1|* for which the uniform asymptotic expansion is used.
+/// (Out of the range, the series and the continued fraction converge in a few hundred terms.)
+const TEMME_MAX_DEVIATION: f64 = 0.3;
+
+/// The coefficients of the power series *c_k(η) = Σ_n c_{k,n} ηⁿ* of the uniform asymptotic expansion.
+const TEMME_C0: [f64; 17] = [
+    -0.3333333333333333, 0.08333333333333333, -0.014814814814814815, 0.0011574074074074073,
+    0.0003527336860670194, -0.0001787551440329218, 3.919263178522438e-05, -2.185448510679992e-06,
+    -1.85406221071516e-06, 8.296711340953087e-07, -1.7665952736826078e-07, 6.707853543401498e-09,
+    1.0261809784240309e-08, -4.382036018453353e-09, 9.14769958223679e-10, -2.5514193994946248e-11,
+    -5.830772132550426e-11,
+];
+const TEMME_C1: [f64; 13] = [
+    -0.001851851851851852, -0.003472222222222222, 0.0026455026455026454, -0.0009902263374485596,
+    0.00020576131687242798, -4.018775720164609e-07, -1.8098550334489977e-05, 7.64916091608111e-06,
+    -1.6120900894563446e-06, 4.647127802807434e-09, 1.378633446915721e-07, -5.752545603517705e-08,
+    1.1951628599778148e-08,
+];
+const TEMME_C2: [f64; 9] = [
+    0.004133597883597883, -0.0026813271604938273, 0.0007716049382716049, 2.0093878600823047e-06,
+    -0.0001073665322636516, 5.2923448829120125e-05, -1.2760635188618728e-05, 3.423578734096138e-08,
+    1.3721957309062934e-06,
+];
+const TEMME_C3: [f64; 5] = [
+    0.0006494341563786008, 0.00022947209362139917, -0.0004691894943952557, 0.00026772063206283885,
+    -7.561801671883977e-05,
+];
+
+/// Return whether *P(s, x)* and *Q(s, x)* are evaluated by the uniform asymptotic expansion.
+fn is_in_the_range_of_temme(s: f64, x: f64) -> bool {
+    s >= TEMME_MIN && s.is_finite() && ((x - s) / s).abs() <= TEMME_MAX_DEVIATION
+}
+
+/// Return *Q(s, x)* (or *P(s, x)* if `lower` is true) for large *s* near *x = s*
+/// by Temme's uniform asymptotic expansion, where *η²/2 = x/s - 1 - log(x/s)*.
+fn q_gamma_temme(s: f64, x: f64, lower: bool) -> f64 {
+    let mu = (x - s) / s;
+    let eta = (-2. * log1pmx(mu)).sqrt().copysign(mu);
+
+    let horner = |c: &[f64]| c.iter().rev().fold(0., |acc, ck| acc * eta + ck);
+    let r = 1. / s;
+    let sum = horner(&TEMME_C0) + r * (horner(&TEMME_C1) + r * (horner(&TEMME_C2) + r * horner(&TEMME_C3)));
+    let tail = (-0.5 * s * eta * eta).exp() / (std::f64::consts::TAU * s).sqrt() * sum;
+
+    let y = eta * (0.5 * s).sqrt();
+    if lower {
+        0.5 * erfc(-y) - tail
+    }else{
+        0.5 * erfc(y) + tail
+    }
+}
+
 //***** incomplete gamma function *****
 /// Return a value of the lower incomplete gamma function *γ(s, x)*.
 pub fn igamma(s: f64, x: f64) -> f64 {
@@ -170,6 +325,7 @@ fn test_the_values_of_igamma_at_non_finite_args(){
 }
 
 #[test]
+#[allow(clippy::redundant_closure)]
 fn test_the_incomplete_gamma_function_properties(){
     should_the_same_mathfn2(
         "γ(s, x) + Γ(s, x) = Γ(s)",
@@ -213,24 +369,12 @@ fn test_the_incomplete_gamma_function_properties(){
         .var0(|v| v.name("s").range(0., 20.).end()).assert();
 
     use std::f64::consts;
-
-    fn factorial(n: f64) -> f64 {
-        let mut i = n.round() as usize;
-        if i == 1 { return 1.}
-
-        let mut result = 1.;
-        while i > 1 {
-            result *= i as f64;
-            i -= 1;
-        }
-
-        result
-    }
+    use crate::factorial_fn::factorial;
 
     should_the_same_mathfn(
         "Γ(s+1, 1) = [e*s!]/e",
             |s| iGamma(s+1., 1.),
-            |s| (consts::E * factorial(s)).floor() / consts::E)
+            |s| (consts::E * factorial(s as u32)).floor() / consts::E)
         .var0(|v| v.name("s").range(1., 10.).is_integer(true).end()).assert();
 
     should_the_same_mathfn2(
@@ -246,7 +390,7 @@ fn test_the_incomplete_gamma_function_properties(){
                     sum += x.powi(k) / kk;
                     k += 1;
                 }
-                return factorial(s-1.) * (-x).exp() * sum;
+                factorial(s as u32 - 1) * (-x).exp() * sum
             })
         .var0(|v| v.name("s").range(1., 10.).is_integer(true).end())
         .var1(|v| v.name("x").end())
@@ -264,6 +408,7 @@ pub fn q_gamma(s: f64, x: f64) -> f64 {
 }
 
 #[test]
+#[allow(clippy::redundant_closure)]
 fn test_the_regularized_incomplete_gamma_function_properties(){
 
     should_the_same_mathfn2(
@@ -281,6 +426,23 @@ fn test_the_regularized_incomplete_gamma_function_properties(){
         .filter(|s, _| s > 0.)
         .var0(|v| v.name("s").end())
         .var1(|v| v.name("x").end()).assert();
+
+    // the values for large s by mpmath, where the series and the continued fraction converge slowly near x = s
+    let table = [
+        ((100.0, 90.0), 0.15822098918643016, 0.8417790108135699),
+        ((100.0, 100.0), 0.5132987982791487, 0.48670120172085135),
+        ((100.0, 105.0), 0.7002453423911563, 0.29975465760884373),
+        ((1000.0, 900.0), 0.0005499022657117829, 0.9994500977342882),
+        ((1000.0, 1000.0), 0.5042052441802155, 0.4957947558197845),
+        ((1000.0, 1050.0), 0.9413288886226819, 0.05867111137731808),
+        ((10000.0, 9000.0), 2.073299202433928e-25, 1.0),
+        ((10000.0, 10000.0), 0.5013298083399552, 0.4986701916600448),
+        ((10000.0, 10500.0), 0.9999995724127545, 4.2758724550596475e-07),
+        ((1000000.0, 998000.0), 0.022696114006736802, 0.9773038859932632),
+        ((1000000.0, 1000000.0), 0.5001329807608725, 0.4998670192391274),
+        ((1000000.0, 1001000.0), 0.8413447863683403, 0.15865521363165971)];
+    assert_reference_values("p_gamma", |(s, x)| p_gamma(s, x), &table.map(|(args, p, _)| (args, p)), EPS);
+    assert_reference_values("q_gamma", |(s, x)| q_gamma(s, x), &table.map(|(args, _, q)| (args, q)), EPS);
 }
 
 //***** Gauss error function *****
@@ -306,7 +468,57 @@ pub fn erfc(x: f64) -> f64 {
     }
 }
 
+/// Return a value of the scaled complementary error function *erfcx(x) = e^{x²}erfc(x)*.
+/// 
+/// The value doesn't underflow for large *x* (*erfcx(x) ~ 1/(x√π)*),
+/// so that it can be used for the tail probabilities and the Mills ratio.
+/// 
+/// ```
+/// use comonjo_mathfn::erfcx;
+/// 
+/// assert_eq!(erfcx(0.), 1.);
+/// assert!((erfcx(30.) - 0.018795888861416751).abs() <= 1e-17);
+/// assert!((erfcx(1e300) * 1e300 * std::f64::consts::PI.sqrt() - 1.).abs() <= 1e-15);
+/// ```
+pub fn erfcx(x: f64) -> f64 {
+    use std::f64::consts::FRAC_2_SQRT_PI;
+
+    if x.is_nan() { return f64::NAN; }
+    if x < 0. {
+        // erfcx(x) = 2e^{x²} - erfcx(-x)
+        return 2. * (x * x).exp() - erfcx(-x);
+    }
+
+    let x2 = x * x;
+    if x2 < 1.5 {
+        x2.exp() * erfc(x)
+    }else if x < 1e8 {
+        // erfc(x) = (x e^{-x²}/√π) * (the Laguerre series of Q(1/2, x²))
+        0.5 * FRAC_2_SQRT_PI * x * q_gamma_scaled(0.5, x2)
+    }else{
+        // erfcx(x) ~ (1 - 1/(2x²))/(x√π)
+        0.5 * FRAC_2_SQRT_PI / x * (1. - 0.5 / x2)
+    }
+}
+
+/// Return a value of the log of the complementary error function *log erfc(x)*.
+/// 
+/// ```
+/// use comonjo_mathfn::ln_erfc;
+/// 
+/// assert_eq!(ln_erfc(0.), 0.);
+/// assert!((ln_erfc(30.) / -903.97411711064388 - 1.).abs() <= 1e-15);
+/// ```
+pub fn ln_erfc(x: f64) -> f64 {
+    if x < 1. {
+        erfc(x).ln()
+    }else{
+        erfcx(x).ln() - x * x
+    }
+}
+
 #[test]
+#[allow(clippy::redundant_closure)]
 fn test_the_error_function_properties(){
     let sqrt_pi_inv = 1. / (std::f64::consts::PI.sqrt());
 
@@ -328,11 +540,52 @@ fn test_the_error_function_properties(){
         .filter(|x| x >= 0.).assert();
 }
 
+#[test]
+fn test_the_scaled_complementary_error_function_properties(){
+    should_the_same_mathfn(
+        "erfcx(x) = e^{x²}erfc(x)",
+            erfcx,
+            |x| (x * x).exp() * erfc(x))
+        .var0(|v| v.range(-5., 25.).end()).assert();
+
+    should_the_same_mathfn(
+        "ln_erfc(x) = log erfc(x)",
+            ln_erfc,
+            |x| erfc(x).ln())
+        .var0(|v| v.range(-5., 25.).end()).assert();
+
+    // x = 10^t
+    should_the_same_mathfn(
+        "erfcx(x) ~ (1 - 1/(2x²) + 3/(4x⁴))/(x√π)",
+            |t| erfcx(10_f64.powf(t)),
+            |t| {
+                let x = 10_f64.powf(t);
+                (1. - 0.5 / (x * x) + 0.75 / x.powi(4)) / (x * std::f64::consts::PI.sqrt())
+            })
+        .var0(|v| v.name("log x").range(4., 300.).end()).assert();
+
+    // the values by mpmath
+    let values = [
+        (-1.,  5.008980080762283,           0.6112323176780705),
+        ( 0.5, 0.6156903441929259,         -0.7350111298370844),
+        ( 1.5, 0.3215854164543175,         -3.3844920895515527),
+        ( 5.,  0.11070463773306863,       -27.200889545537436),
+        (30.,  0.01879588886141675,      -903.9741171106439),
+        (1e3,  0.0005641893014533876, -1000007.4801207219)];
+    assert_reference_values("erfcx", erfcx, &values.map(|(x, y, _)| (x, y)), EPS);
+    assert_reference_values("ln_erfc", ln_erfc, &values.map(|(x, _, y)| (x, y)), EPS);
+
+    assert_eq!(erfcx(-30.), f64::INFINITY);
+    assert_eq!(ln_erfc(1e300), f64::NEG_INFINITY);
+    assert!(erfcx(f64::NAN).is_nan());
+}
+
 //***** normal distribution *****
 /// Return a value of the lower CDF (cumulative distribution function) of the normal distribution.
 /// 
 /// Ref: 『改訂新版 Cによる標準アルゴリズム事典』不完全ガンマ関数 (incomplete gamma function) igamma.c
 pub fn p_normal(x: f64) -> f64 {
+    if x.is_infinite() { return if x > 0. { 1. }else{ 0. }; }
     if x >= 0. {
         0.5*(1. + p_gamma_normalizable(0.5, 0.5*x*x, LOG_PI_BY2))
     }else{
@@ -344,6 +597,7 @@ pub fn p_normal(x: f64) -> f64 {
 /// 
 /// Ref: 『改訂新版 Cによる標準アルゴリズム事典』不完全ガンマ関数 (incomplete gamma function) igamma.c
 pub fn q_normal(x: f64) -> f64 {
+    if x.is_infinite() { return if x > 0. { 0. }else{ 1. }; }
     if x >= 0. {
         0.5*q_gamma_normalizable(0.5, 0.5*x*x, LOG_PI_BY2)
     }else{
@@ -351,7 +605,42 @@ pub fn q_normal(x: f64) -> f64 {
     }
 }
 
+/// Return a value of the log of the lower CDF of the normal distribution.
+/// 
+/// The value is accurate even in the tail where `p_normal` underflows (up to *|x| ~ 1e150*).
+/// 
+/// ```
+/// use comonjo_mathfn::log_p_normal;
+/// 
+/// assert!((log_p_normal(-40.) / -804.6084420137538 - 1.).abs() <= 1e-15);
+/// assert!((log_p_normal(-1e150) / -5e299 - 1.).abs() <= 1e-15);
+/// ```
+pub fn log_p_normal(x: f64) -> f64 {
+    log_q_normal(-x)
+}
+
+/// Return a value of the log of the upper CDF of the normal distribution.
+/// 
+/// For *x ≥ 5*, the value is evaluated as *log erfc(x/√2) - log 2* with `ln_erfc`,
+/// which is based on the asymptotic form *erfc(x) ~ e^{-x²}/(x√π)*.
+/// 
+/// ```
+/// use comonjo_mathfn::log_q_normal;
+/// 
+/// assert!((log_q_normal(40.) / -804.6084420137538 - 1.).abs() <= 1e-15);
+/// ```
+pub fn log_q_normal(x: f64) -> f64 {
+    if x < 0. {
+        (-p_normal(x)).ln_1p()
+    }else if x < 5. {
+        q_normal(x).ln()
+    }else{
+        ln_erfc(x * std::f64::consts::FRAC_1_SQRT_2) - std::f64::consts::LN_2
+    }
+}
+
 #[test]
+#[allow(clippy::redundant_closure)]
 fn test_the_normal_distribution_cdf_properties(){
     should_the_same_mathfn(
         "q_normal(x) = 1 - p_normal(x)", 
@@ -364,6 +653,44 @@ fn test_the_normal_distribution_cdf_properties(){
         "p_normal(x) = (1 + erf(x/√2))/2",
             |x| p_normal(x),
             |x| (1. + erf(x * FRAC_1_SQRT_2)) / 2.).assert();
+
+    assert_eq!((p_normal(f64::NEG_INFINITY), p_normal(f64::INFINITY)), (0., 1.));
+    assert_eq!((q_normal(f64::NEG_INFINITY), q_normal(f64::INFINITY)), (1., 0.));
+}
+
+#[test]
+fn test_the_log_normal_distribution_cdf_properties(){
+    should_the_same_mathfn(
+        "log_q_normal(x) = log q_normal(x)",
+            log_q_normal,
+            |x| q_normal(x).ln())
+        .var0(|v| v.range(-5., 35.).end()).assert();
+
+    should_the_same_mathfn(
+        "log_p_normal(x) = log_q_normal(-x)",
+            log_p_normal,
+            |x| log_q_normal(-x)).assert();
+
+    // x = 10^t
+    should_the_same_mathfn(
+        "log_q_normal(x) ~ -x²/2 - log(x√2π) + log(1 - 1/x² + 3/x⁴)",
+            |t| log_q_normal(10_f64.powf(t)),
+            |t| {
+                let x = 10_f64.powf(t);
+                let x2 = x * x;
+                -0.5 * x2 - (x * (2. * std::f64::consts::PI).sqrt()).ln() + (-1. / x2 + 3. / (x2 * x2)).ln_1p()
+            })
+        .var0(|v| v.name("log x").range(2., 150.).end()).assert();
+
+    // the values by mpmath
+    assert_reference_values("log_q_normal", log_q_normal, &[
+        (-3., -0.0013508099647481938),
+        ( 0., -std::f64::consts::LN_2),
+        ( 1., -1.8410216450092636),
+        ( 5., -15.064998393988725),
+        (10., -53.23128515051247),
+        (40., -804.6084420137538),
+        (1e3, -500007.82669481216)], EPS);
 }
 
 //***** chi square distribution *****
@@ -382,6 +709,7 @@ pub fn q_chi2(x: f64, n_f: f64) -> f64 {
 }
 
 #[test]
+#[allow(clippy::redundant_closure)]
 fn test_the_chi_square_distribution_cdf_properties(){
     should_the_same_mathfn2(
         "q_chi2(x, k) = 1 - p_chi2(x, k)",
@@ -396,6 +724,242 @@ fn test_the_chi_square_distribution_cdf_properties(){
         .var1(|v| v.name("k").range(1., 10.).is_integer(true).end()).assert();  
 }
 
+//***** gamma distribution *****
+fn is_out_of_domain_of_gamma_dist(x: f64, shape: f64, scale: f64) -> bool {
+    x.is_nan() || shape.is_nan() || scale.is_nan() || shape <= 0. || scale <= 0. || shape.is_infinite() || scale.is_infinite()
+}
+
+/// Return the logarithm of the probability density function of the gamma distribution
+/// with the shape *k* and the scale *θ*, that is, *log(x^{k-1}e^{-x/θ}/(Γ(k)θ^k))*.
+/// 
+/// ```
+/// use comonjo_mathfn::log_gamma_pdf;
+/// 
+/// assert!((log_gamma_pdf(5e5, 5e5, 2.) - (-96581.07040036123)).abs() <= 1e-10);
+/// assert_eq!(log_gamma_pdf(-1., 2., 1.), f64::NEG_INFINITY);
+/// ```
+pub fn log_gamma_pdf(x: f64, shape: f64, scale: f64) -> f64 {
+    if is_out_of_domain_of_gamma_dist(x, shape, scale) { return f64::NAN; }
+    if x < 0. || x.is_infinite() { return f64::NEG_INFINITY; }
+    if x == 0. {
+        return if shape < 1. { f64::INFINITY }else if shape > 1. { f64::NEG_INFINITY }else{ -scale.ln() };
+    }
+
+    log_power_exp_by_gamma(shape, x / scale, log_gamma(shape)) - x.ln()
+}
+
+/// Return a value of the probability density function of the gamma distribution
+/// with the shape *k* and the scale *θ* (the rate *1/θ*), that is, *x^{k-1}e^{-x/θ}/(Γ(k)θ^k)*.
+/// 
+/// ```
+/// use comonjo_mathfn::gamma_pdf;
+/// 
+/// assert!((gamma_pdf(2.5, 3., 1.5) - 0.1748848174421869).abs() <= 1e-15);
+/// // x^{k-1}e^{-x}/Γ(k) overflows in the intermediate values
+/// assert!((gamma_pdf(1000., 1000., 1.) - 0.012614611348721499).abs() <= 1e-15);
+/// ```
+pub fn gamma_pdf(x: f64, shape: f64, scale: f64) -> f64 {
+    log_gamma_pdf(x, shape, scale).exp()
+}
+
+/// Return a value of the lower CDF of the gamma distribution
+/// with the shape *k* and the scale *θ* (the rate *1/θ*), that is, *P(k, x/θ)*.
+/// 
+/// ```
+/// use comonjo_mathfn::p_gamma_dist;
+/// 
+/// assert!((p_gamma_dist(2.5, 3., 1.5) - 0.23400449960322142).abs() <= 1e-15);
+/// assert_eq!(p_gamma_dist(-1., 3., 1.5), 0.);
+/// ```
+pub fn p_gamma_dist(x: f64, shape: f64, scale: f64) -> f64 {
+    if is_out_of_domain_of_gamma_dist(x, shape, scale) { return f64::NAN; }
+    if x <= 0. { return 0.; }
+    if x == f64::INFINITY { return 1.; }
+
+    p_gamma(shape, x / scale)
+}
+
+/// Return a value of the upper CDF of the gamma distribution
+/// with the shape *k* and the scale *θ* (the rate *1/θ*), that is, *Q(k, x/θ)*.
+/// 
+/// ```
+/// use comonjo_mathfn::q_gamma_dist;
+/// 
+/// assert!((q_gamma_dist(2.5, 3., 1.5) - 0.7659955003967786).abs() <= 1e-15);
+/// ```
+pub fn q_gamma_dist(x: f64, shape: f64, scale: f64) -> f64 {
+    if is_out_of_domain_of_gamma_dist(x, shape, scale) { return f64::NAN; }
+    if x <= 0. { return 1.; }
+    if x == f64::INFINITY { return 0.; }
+
+    q_gamma(shape, x / scale)
+}
+
+/// Return the logarithm of the probability density function of the gamma distribution
+/// with the shape *k* and the rate *β*, that is, *log(β^k x^{k-1}e^{-βx}/Γ(k))*.
+/// 
+/// ```
+/// use comonjo_mathfn::{log_gamma_pdf, log_gamma_pdf_rate};
+/// 
+/// assert_eq!(log_gamma_pdf_rate(2.5, 3., 4.), log_gamma_pdf(2.5, 3., 0.25));
+/// assert_eq!(log_gamma_pdf_rate(0., 1., 2.), 2_f64.ln());
+/// ```
+pub fn log_gamma_pdf_rate(x: f64, shape: f64, rate: f64) -> f64 {
+    if is_out_of_domain_of_gamma_dist(x, shape, rate) { return f64::NAN; }
+    if x < 0. || x.is_infinite() { return f64::NEG_INFINITY; }
+    if x == 0. {
+        return if shape < 1. { f64::INFINITY }else if shape > 1. { f64::NEG_INFINITY }else{ rate.ln() };
+    }
+
+    log_power_exp_by_gamma(shape, x * rate, log_gamma(shape)) - x.ln()
+}
+
+/// Return a value of the probability density function of the gamma distribution
+/// with the shape *k* and the rate *β*, that is, *β^k x^{k-1}e^{-βx}/Γ(k)*.
+/// 
+/// ```
+/// use comonjo_mathfn::gamma_pdf_rate;
+/// 
+/// assert!((gamma_pdf_rate(2.5, 3., 1. / 1.5) - 0.1748848174421869).abs() <= 1e-15);
+/// ```
+pub fn gamma_pdf_rate(x: f64, shape: f64, rate: f64) -> f64 {
+    log_gamma_pdf_rate(x, shape, rate).exp()
+}
+
+/// Return a value of the lower CDF of the gamma distribution
+/// with the shape *k* and the rate *β*, that is, *P(k, βx)*.
+/// 
+/// ```
+/// use comonjo_mathfn::p_gamma_dist_rate;
+/// 
+/// assert!((p_gamma_dist_rate(2.5, 3., 1. / 1.5) - 0.23400449960322142).abs() <= 1e-15);
+/// assert_eq!(p_gamma_dist_rate(f64::INFINITY, 3., 2.), 1.);
+/// ```
+pub fn p_gamma_dist_rate(x: f64, shape: f64, rate: f64) -> f64 {
+    if is_out_of_domain_of_gamma_dist(x, shape, rate) { return f64::NAN; }
+    if x <= 0. { return 0.; }
+    if x == f64::INFINITY { return 1.; }
+
+    p_gamma(shape, x * rate)
+}
+
+/// Return a value of the upper CDF of the gamma distribution
+/// with the shape *k* and the rate *β*, that is, *Q(k, βx)*.
+/// 
+/// ```
+/// use comonjo_mathfn::q_gamma_dist_rate;
+/// 
+/// assert!((q_gamma_dist_rate(2.5, 3., 1. / 1.5) - 0.7659955003967786).abs() <= 1e-15);
+/// ```
+pub fn q_gamma_dist_rate(x: f64, shape: f64, rate: f64) -> f64 {
+    if is_out_of_domain_of_gamma_dist(x, shape, rate) { return f64::NAN; }
+    if x <= 0. { return 1.; }
+    if x == f64::INFINITY { return 0.; }
+
+    q_gamma(shape, x * rate)
+}
+
+#[test]
+fn test_the_values_of_gamma_dist_out_of_the_domain(){
+    for (shape, scale) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if shape > 0. && shape.is_finite() && scale > 0. && scale.is_finite() { continue; }
+        assert!(gamma_pdf(1., shape, scale).is_nan(), "gamma_pdf(1, {}, {}) = NaN", shape, scale);
+        assert!(p_gamma_dist(1., shape, scale).is_nan(), "p_gamma_dist(1, {}, {}) = NaN", shape, scale);
+        assert!(q_gamma_dist(1., shape, scale).is_nan(), "q_gamma_dist(1, {}, {}) = NaN", shape, scale);
+    }
+    assert!(gamma_pdf(f64::NAN, 1., 1.).is_nan());
+    assert!(p_gamma_dist(f64::NAN, 1., 1.).is_nan());
+    assert!(q_gamma_dist(f64::NAN, 1., 1.).is_nan());
+    assert_eq!((p_gamma_dist(f64::INFINITY, 2., 1.), q_gamma_dist(f64::INFINITY, 2., 1.)), (1., 0.));
+
+    for (shape, rate) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if shape > 0. && shape.is_finite() && rate > 0. && rate.is_finite() { continue; }
+        assert!(gamma_pdf_rate(1., shape, rate).is_nan(), "gamma_pdf_rate(1, {}, {}) = NaN", shape, rate);
+        assert!(p_gamma_dist_rate(1., shape, rate).is_nan(), "p_gamma_dist_rate(1, {}, {}) = NaN", shape, rate);
+        assert!(q_gamma_dist_rate(1., shape, rate).is_nan(), "q_gamma_dist_rate(1, {}, {}) = NaN", shape, rate);
+    }
+    assert_eq!((p_gamma_dist_rate(f64::INFINITY, 2., 1.), q_gamma_dist_rate(f64::INFINITY, 2., 1.)), (1., 0.));
+}
+
+#[test]
+fn test_the_gamma_distribution_properties(){
+    should_the_same_mathfn3(
+        "gamma_pdf(x, k, θ) = x^{k-1}e^{-x/θ}/(Γ(k)θ^k)",
+            gamma_pdf,
+            |x, k, theta| x.powf(k - 1.) * (-x / theta).exp() / (gamma(k) * theta.powf(k)))
+        .var0(|v| v.name("x").range(0.1, 20.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("θ").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn2(
+        "gamma_pdf(x, 1, θ) = e^{-x/θ}/θ",
+            |x, theta| gamma_pdf(x, 1., theta),
+            |x, theta| (-x / theta).exp() / theta)
+        .var0(|v| v.name("x").range(0., 20.).end())
+        .var1(|v| v.name("θ").range(0.1, 5.).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_gamma_dist(x, k, θ) = p_gamma(k, x/θ)",
+            p_gamma_dist,
+            |x, k, theta| p_gamma(k, x / theta))
+        .var0(|v| v.name("x").range(0., 20.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("θ").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_gamma_dist(x, k, θ) = 1 - p_gamma_dist(x, k, θ)",
+            q_gamma_dist,
+            |x, k, theta| 1. - p_gamma_dist(x, k, theta))
+        .var0(|v| v.name("x").range(-1., 20.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("θ").range(0.1, 5.).n(20).end()).assert();
+
+    // the chi-square distribution with n degrees of freedom is Gamma(n/2, 2)
+    should_the_same_mathfn2(
+        "p_gamma_dist(x, n/2, 2) = p_chi2(x, n)",
+            |x, n| p_gamma_dist(x, 0.5 * n, 2.),
+            p_chi2)
+        .var0(|v| v.name("x").range(0., 20.).end())
+        .var1(|v| v.name("n").range(1., 10.).is_integer(true).end()).assert();
+
+    // the values by mpmath (large shapes overflow x^{k-1} and Γ(k))
+    assert_reference_values("log_gamma_pdf", |(x, k, theta)| log_gamma_pdf(x, k, theta), &[
+        ((2.5,  3.,   1.5), -1.743627707802795),
+        ((1000., 1000., 1.), -4.372899506026297),
+        ((5e5,  5e5,  2.),  -96581.07040036123),
+        ((1e-3, 0.5,  2.),  2.5344391062863956),
+        ((300., 200., 1.),  -22.880957369273425)], EPS);
+
+    // the shape–rate parameterization is Gamma(k, 1/β)
+    should_the_same_mathfn3(
+        "gamma_pdf_rate(x, k, β) = gamma_pdf(x, k, 1/β)",
+            gamma_pdf_rate,
+            |x, k, rate| gamma_pdf(x, k, 1. / rate))
+        .var0(|v| v.name("x").range(0., 20.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("β").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_gamma_dist_rate(x, k, β) = p_gamma(k, βx)",
+            p_gamma_dist_rate,
+            |x, k, rate| p_gamma(k, rate * x))
+        .var0(|v| v.name("x").range(0., 20.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("β").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_gamma_dist_rate(x, k, β) = 1 - p_gamma_dist_rate(x, k, β)",
+            q_gamma_dist_rate,
+            |x, k, rate| 1. - p_gamma_dist_rate(x, k, rate))
+        .var0(|v| v.name("x").range(-1., 20.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("β").range(0.1, 5.).n(20).end()).assert();
+
+    assert_eq!(gamma_pdf(0., 0.5, 1.), f64::INFINITY);
+    assert_eq!(gamma_pdf(0., 2., 1.), 0.);
+    assert_eq!(gamma_pdf(0., 1., 2.), 0.5);
+}
+
 #[cfg(test)]
 use super::test_util::*;
 #[cfg(test)]
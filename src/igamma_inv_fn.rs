@@ -0,0 +1,825 @@
+// The tests refer to
+// <a href="https://en.m.wikipedia.org/wiki/Incomplete_gamma_function">Incomplete gamma function</a>
+
+use crate::gamma_fn::log_gamma;
+use crate::igamma_fn::{p_normal, q_normal, log_power_exp_by_gamma, hyper_geometric_series, max_iterations, q_gamma_scaled};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Return a rough approximation of the normal quantile (the absolute error < 4.5e-4),
+/// where *q = 1 - p* is given to keep the precision of the upper tail.
+pub(crate) fn rough_normal_quantile(p: f64, q: f64) -> f64 {
+    let pp = if p < 0.5 { p }else{ q };
+    let t = (-2. * pp.ln()).sqrt();
+    let z = t - (2.515517 + t * (0.802853 + t * 0.010328))
+                / (1. + t * (1.432788 + t * (0.189269 + t * 0.001308)));
+    if p < 0.5 { -z }else{ z }
+}
+
+/// Return an initial guess of *x* such that *P(s, x) = p* (and *Q(s, x) = q*).
+fn initial_guess_of_p_gamma_inv(s: f64, p: f64, q: f64, log_gamma_s: f64) -> f64 {
+    // lower tail: P(s, x) ~ x^s/Γ(s+1)
+    let x_lower = ((p.ln() + log_gamma_s + s.ln()) / s).exp();
+    if x_lower < 0.2 * (s + 1.) { return x_lower; }
+
+    if s > 1. {
+        // Wilson–Hilferty approximation
+        let z = rough_normal_quantile(p, q);
+        let t = 1. - 1. / (9. * s) + z / (3. * s.sqrt());
+        let x = s * t * t * t;
+        if x > 0. { return x; }
+    }
+
+    // upper tail: Q(s, x) ~ x^{s-1}e^{-x}/Γ(s)
+    let c = -q.ln() - log_gamma_s;
+    let mut x = c.max(1.);
+    for _ in 0..3 { x = (c + (s - 1.) * x.ln()).max(1.); }
+    x
+}
+
+/// Return *log P(s, x)* (or *log Q(s, x)* if `upper` is true) for positive *x*,
+/// which doesn't underflow even if *P(s, x)* does.
+fn log_p_gamma(s: f64, x: f64, log_gamma_s: f64, upper: bool) -> f64 {
+    if x < 1. + s {
+        let series = hyper_geometric_series(1., |k| x / (s + k), 1., max_iterations(s));
+        let log_p = log_power_exp_by_gamma(s, x, log_gamma_s) - s.ln() + series.ln();
+        if upper { (-log_p.exp()).ln_1p() }else{ log_p }
+    }else{
+        let log_q = log_power_exp_by_gamma(s, x, log_gamma_s) + q_gamma_scaled(s, x).ln();
+        if upper { log_q }else{ (-log_q.exp()).ln_1p() }
+    }
+}
+
+/// Solve *log P(s, x) = log p* if *p ≤ 1/2*, otherwise *log Q(s, x) = log q*, by the Halley method.
+fn solve_p_gamma(s: f64, p: f64, q: f64) -> f64 {
+    let log_gamma_s = log_gamma(s);
+    let upper = p > 0.5;
+    let log_target = if upper { q.ln() }else{ p.ln() };
+    let mut x = initial_guess_of_p_gamma_inv(s, p, q, log_gamma_s);
+
+    for _ in 0..MAX_ITERATIONS {
+        // f(x) = log P(s, x) - log p, f'(x) = x^{s-1}e^{-x}/(Γ(s)P(s, x)) and f''/f' = (s-1)/x - 1 - f'
+        // (the sign of f' is reversed for Q)
+        let log_x = log_p_gamma(s, x, log_gamma_s, upper);
+        let f = log_x - log_target;
+        let mut df = (log_power_exp_by_gamma(s, x, log_gamma_s) - x.ln() - log_x).exp();
+        if upper { df = -df; }
+        if f == 0. || df == 0. || !df.is_finite() || !f.is_finite() { return x; }
+
+        let u = f / df;
+        let step = u / (1. - 0.5 * (u * ((s - 1.) / x - 1. - df)).min(1.));
+
+        let prev = x;
+        x -= step;
+        if x <= 0. { x = 0.5 * prev; }
+        if (x - prev).abs() <= 1e-15 * x { break; }
+    }
+
+    x
+}
+
+/// Return *x* such that *P(s, x) = p*, the inverse of the regularized gamma function.
+/// (The argument *s* must be positive and *0 ≤ p ≤ 1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{p_gamma, p_gamma_inv};
+/// 
+/// let x = p_gamma_inv(3., 0.25);
+/// assert!((p_gamma(3., x) - 0.25).abs() <= 1e-15);
+/// 
+/// assert_eq!(p_gamma_inv(3., 0.), 0.);
+/// assert_eq!(p_gamma_inv(3., 1.), f64::INFINITY);
+/// ```
+pub fn p_gamma_inv(s: f64, p: f64) -> f64 {
+    if s.is_nan() || p.is_nan() || s <= 0. || !(0. ..=1.).contains(&p) { return f64::NAN; }
+    if p == 0. { return 0.; }
+    if p == 1. { return f64::INFINITY; }
+    if s.is_infinite() { return f64::INFINITY; }
+
+    solve_p_gamma(s, p, 1. - p)
+}
+
+/// Return *x* such that *Q(s, x) = q*, the inverse of the regularized gamma function *Q*.
+/// (The argument *s* must be positive and *0 ≤ q ≤ 1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{q_gamma, q_gamma_inv};
+/// 
+/// let x = q_gamma_inv(3., 1e-100);
+/// assert!((q_gamma(3., x) / 1e-100 - 1.).abs() <= 1e-13);
+/// ```
+pub fn q_gamma_inv(s: f64, q: f64) -> f64 {
+    if s.is_nan() || q.is_nan() || s <= 0. || !(0. ..=1.).contains(&q) { return f64::NAN; }
+    if q == 1. { return 0.; }
+    if q == 0. { return f64::INFINITY; }
+    if s.is_infinite() { return f64::INFINITY; }
+
+    solve_p_gamma(s, 1. - q, q)
+}
+
+/// Return a root of *f* in the bracket *[a, b]*, where *f(a)* and *f(b)* have opposite signs,
+/// by the Illinois method (the modified regula falsi).
+/// If the function value is not finite, the bracket is bisected.
+pub(crate) fn find_root_in_bracket<F>(f: F, mut a: f64, mut b: f64) -> f64
+    where F: Fn(f64) -> f64
+{
+    let mut fa = f(a);
+    let mut fb = f(b);
+    if fa == 0. { return a; }
+    if fb == 0. { return b; }
+
+    for _ in 0..MAX_ITERATIONS * 2 {
+        let mut c = if fa.is_finite() && fb.is_finite() { (a * fb - b * fa) / (fb - fa) }else{ 0.5 * (a + b) };
+        if !(a.min(b) < c && c < a.max(b)) { c = 0.5 * (a + b); }
+
+        let fc = f(c);
+        if fc == 0. { return c; }
+        if (fc < 0.) == (fb < 0.) {
+            fa *= 0.5;
+        }else{
+            a = b; fa = fb;
+        }
+        b = c; fb = fc;
+
+        if (b - a).abs() <= 2. * f64::EPSILON * b.abs() { break; }
+    }
+
+    b
+}
+
+/// Solve *log P(s, x) = log p* if *p ≤ 1/2*, otherwise *log Q(s, x) = log q*, for *s*.
+/// 
+/// Since *P(s, x)* is decreasing in *s* (from 1 to 0), the root is bracketed
+/// by doubling or halving the initial guess *s = x*.
+fn solve_p_gamma_for_s(x: f64, p: f64, q: f64) -> f64 {
+    let upper = p > 0.5;
+    // g(s) is decreasing in s
+    let g = |s: f64| {
+        let log_x = log_p_gamma(s, x, log_gamma(s), upper);
+        if upper { q.ln() - log_x }else{ log_x - p.ln() }
+    };
+
+    let mut lo = x.max(1.);
+    let mut hi = lo;
+    if g(lo) > 0. {
+        while g(hi) > 0. {
+            lo = hi; hi *= 2.;
+            if hi > f64::MAX / 2. { return f64::INFINITY; }
+        }
+    }else{
+        while g(lo) < 0. {
+            hi = lo; lo *= 0.5;
+            if lo < f64::MIN_POSITIVE { return 0.; }
+        }
+    }
+
+    find_root_in_bracket(g, lo, hi)
+}
+
+/// Return *s* such that *P(s, x) = p*, the inverse of the regularized gamma function
+/// in the shape parameter. (The argument *x* must be positive and *0 ≤ p ≤ 1*.)
+/// 
+/// *P(s, x)* decreases from 1 to 0 as *s* increases, so the root is found by a bracketing method.
+/// 
+/// ```
+/// use comonjo_mathfn::{p_gamma, p_gamma_inv_s};
+/// 
+/// let s = p_gamma_inv_s(3., 0.25);
+/// assert!((p_gamma(s, 3.) - 0.25).abs() <= 1e-15);
+/// 
+/// assert_eq!(p_gamma_inv_s(3., 1.), 0.);
+/// assert_eq!(p_gamma_inv_s(3., 0.), f64::INFINITY);
+/// ```
+pub fn p_gamma_inv_s(x: f64, p: f64) -> f64 {
+    if x.is_nan() || p.is_nan() || x <= 0. || x.is_infinite() || !(0. ..=1.).contains(&p) { return f64::NAN; }
+    if p == 1. { return 0.; }
+    if p == 0. { return f64::INFINITY; }
+
+    solve_p_gamma_for_s(x, p, 1. - p)
+}
+
+/// Return *s* such that *Q(s, x) = q*, the inverse of the regularized gamma function *Q*
+/// in the shape parameter. (The argument *x* must be positive and *0 ≤ q ≤ 1*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{q_gamma, q_gamma_inv_s};
+/// 
+/// // Q(k+1, λ) is the Poisson CDF at k, so k = s - 1 is the (continuous) 95% quantile for λ = 17
+/// let s = q_gamma_inv_s(17.0, 0.95);
+/// assert!((q_gamma(s, 17.0) - 0.95).abs() <= 1e-15);
+/// ```
+pub fn q_gamma_inv_s(x: f64, q: f64) -> f64 {
+    if x.is_nan() || q.is_nan() || x <= 0. || x.is_infinite() || !(0. ..=1.).contains(&q) { return f64::NAN; }
+    if q == 0. { return 0.; }
+    if q == 1. { return f64::INFINITY; }
+
+    solve_p_gamma_for_s(x, 1. - q, q)
+}
+
+//***** inverse error function *****
+/// Return a value of the inverse error function, that is, *x* such that *erf(x) = y*.
+/// (The argument must be in *[-1, 1]*.)
+/// 
+/// Since *erf(x) = P(1/2, x²)* for *x ≥ 0*, the value is evaluated as *√P⁻¹(1/2, |y|)*.
+/// 
+/// ```
+/// use comonjo_mathfn::{erf, erf_inv};
+/// 
+/// assert_eq!(erf_inv(0.), 0.);
+/// assert_eq!(erf_inv(1.), f64::INFINITY);
+/// assert_eq!(erf_inv(-1.), f64::NEG_INFINITY);
+/// assert!((erf(erf_inv(0.5)) - 0.5).abs() <= 1e-15);
+/// ```
+pub fn erf_inv(y: f64) -> f64 {
+    if y.is_nan() || y.abs() > 1. { return f64::NAN; }
+    if y.abs() == 1. { return f64::INFINITY.copysign(y); }
+
+    // erf⁻¹(y) = (√π/2)(y + πy³/12 + 7π²y⁵/480 + ...)
+    if y.abs() < 1e-5 {
+        use std::f64::consts::PI;
+        return 0.5 * PI.sqrt() * y * (1. + PI / 12. * y * y);
+    }
+
+    p_gamma_inv(0.5, y.abs()).sqrt().copysign(y)
+}
+
+/// Return a value of the inverse complementary error function, that is, *x* such that *erfc(x) = y*.
+/// (The argument must be in *[0, 2]*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{erfc, erfc_inv};
+/// 
+/// assert_eq!(erfc_inv(1.), 0.);
+/// assert_eq!(erfc_inv(0.), f64::INFINITY);
+/// assert_eq!(erfc_inv(2.), f64::NEG_INFINITY);
+/// assert!((erfc(erfc_inv(1e-300)) / 1e-300 - 1.).abs() <= 1e-13);
+/// ```
+pub fn erfc_inv(y: f64) -> f64 {
+    if y.is_nan() || !(0. ..=2.).contains(&y) { return f64::NAN; }
+    if y <= 1. {
+        q_gamma_inv(0.5, y).sqrt()
+    }else{
+        -erf_inv(y - 1.)
+    }
+}
+
+//***** inverse normal distribution *****
+/// Evaluate *(c[0] + c[1]x + ... + c[7]x^7)*.
+fn poly7(c: &[f64; 8], x: f64) -> f64 {
+    c.iter().rev().fold(0., |acc, &ci| acc * x + ci)
+}
+
+/// Return the normal quantile, where *q* = 1 - p is given to keep the precision of the upper tail.
+/// 
+/// Ref: M. J. Wichura, Algorithm AS 241 (PPND16) (1988)
+#[allow(clippy::excessive_precision)]
+fn ppnd16(p: f64, q: f64) -> f64 {
+    const A: [f64; 8] = [3.3871328727963666080e0, 1.3314166789178437745e2, 1.9715909503065514427e3, 1.3731693765509461125e4,
+                         4.5921953931549871457e4, 6.7265770927008700853e4, 3.3430575583588128105e4, 2.5090809287301226727e3];
+    const B: [f64; 8] = [1., 4.2313330701600911252e1, 6.8718700749205790830e2, 5.3941960214247511077e3,
+                         2.1213794301586595867e4, 3.9307895800092710610e4, 2.8729085735721942674e4, 5.2264952788528545610e3];
+    const C: [f64; 8] = [1.42343711074968357734e0, 4.63033784615654529590e0, 5.76949722146069140550e0, 3.64784832476320460504e0,
+                         1.27045825245236838258e0, 2.41780725177450611770e-1, 2.27238449892691845833e-2, 7.74545014278341407640e-4];
+    const D: [f64; 8] = [1., 2.05319162663775882187e0, 1.67638483018380384940e0, 6.89767334985100004550e-1,
+                         1.48103976427480074590e-1, 1.51986665636164571966e-2, 5.47593808499534494600e-4, 1.05075007164441684324e-9];
+    const E: [f64; 8] = [6.65790464350110377720e0, 5.46378491116411436990e0, 1.78482653991729133580e0, 2.96560571828504891230e-1,
+                         2.65321895265761230930e-2, 1.24266094738807843860e-3, 2.71155556874348757815e-5, 2.01033439929228813265e-7];
+    const F: [f64; 8] = [1., 5.99832206555887937690e-1, 1.36929880922735805310e-1, 1.48753612908506148525e-2,
+                         7.86869131145613259100e-4, 1.84631831751005468180e-5, 1.42151175831644588870e-7, 2.04426310338993978564e-15];
+
+    let d = p - 0.5;
+    if d.abs() <= 0.425 {
+        let r = 0.180625 - d * d;
+        return d * poly7(&A, r) / poly7(&B, r);
+    }
+
+    let mut r = (-p.min(q).ln()).sqrt();
+    let x = if r <= 5. {
+        r -= 1.6;
+        poly7(&C, r) / poly7(&D, r)
+    }else{
+        r -= 5.;
+        poly7(&E, r) / poly7(&F, r)
+    };
+    if d < 0. { -x }else{ x }
+}
+
+/// Return a value of the quantile function (the inverse of the lower CDF) of the standard normal distribution.
+/// (The argument must be in *[0, 1]*.)
+/// 
+/// The value by Wichura's algorithm AS241 is polished by one step of the Halley method against `p_normal`.
+/// 
+/// ```
+/// use comonjo_mathfn::{p_normal, p_normal_inv};
+/// 
+/// assert_eq!(p_normal_inv(0.5), 0.);
+/// assert_eq!(p_normal_inv(0.), f64::NEG_INFINITY);
+/// assert_eq!(p_normal_inv(1.), f64::INFINITY);
+/// assert!((p_normal_inv(0.975) - 1.959963984540054).abs() <= 1e-15);
+/// assert!((p_normal(p_normal_inv(1e-300)) / 1e-300 - 1.).abs() <= 1e-13);
+/// ```
+pub fn p_normal_inv(p: f64) -> f64 {
+    if p.is_nan() || !(0. ..=1.).contains(&p) { return f64::NAN; }
+    if p == 0. { return f64::NEG_INFINITY; }
+    if p == 1. { return f64::INFINITY; }
+    if p == 0.5 { return 0.; }
+
+    let q = 1. - p;
+    let x = ppnd16(p, q);
+
+    // Halley step: f(x) = P(x) - p, f'(x) = φ(x), f''/f' = -x
+    let f = if p < 0.5 { p_normal(x) - p }else{ q - q_normal(x) };
+    let df = (-0.5 * x * x).exp() / (2. * std::f64::consts::PI).sqrt();
+    if df == 0. { return x; }
+    let u = f / df;
+    x - u / (1. + 0.5 * x * u)
+}
+
+/// Return a value of the quantile function of the upper CDF of the standard normal distribution,
+/// that is, *x* such that `q_normal(x)` *= q*. (The argument must be in *[0, 1]*.)
+/// 
+/// ```
+/// use comonjo_mathfn::q_normal_inv;
+/// 
+/// assert!((q_normal_inv(0.025) - 1.959963984540054).abs() <= 1e-15);
+/// ```
+pub fn q_normal_inv(q: f64) -> f64 {
+    -p_normal_inv(q)
+}
+
+//***** inverse chi square distribution *****
+/// Return a value of the quantile function (the inverse of the lower CDF) of the chi-square distribution
+/// with *n_f* degrees of freedom, that is, *2P⁻¹(n_f/2, p)*.
+/// 
+/// ```
+/// use comonjo_mathfn::p_chi2_inv;
+/// 
+/// assert!((p_chi2_inv(0.95, 10.) - 18.307038053275143).abs() <= 1e-13);
+/// assert_eq!(p_chi2_inv(0., 10.), 0.);
+/// ```
+pub fn p_chi2_inv(p: f64, n_f: f64) -> f64 {
+    2. * p_gamma_inv(0.5 * n_f, p)
+}
+
+/// Return a value of the quantile function of the upper CDF of the chi-square distribution
+/// with *n_f* degrees of freedom, that is, *2Q⁻¹(n_f/2, q)*.
+/// 
+/// ```
+/// use comonjo_mathfn::q_chi2_inv;
+/// 
+/// // the critical value at the significance level 5% for 1 degree of freedom
+/// assert!((q_chi2_inv(0.05, 1.) - 3.8414588206941245).abs() <= 1e-14);
+/// ```
+pub fn q_chi2_inv(q: f64, n_f: f64) -> f64 {
+    2. * q_gamma_inv(0.5 * n_f, q)
+}
+
+//***** inverse gamma distribution *****
+/// Return a value of the quantile function (the inverse of the lower CDF) of the gamma distribution
+/// with the shape *k* and the scale *θ* (the rate *1/θ*), that is, *θP⁻¹(k, p)*.
+/// 
+/// ```
+/// use comonjo_mathfn::{p_gamma_dist, p_gamma_dist_inv};
+/// 
+/// // Gamma(4, 2) is the chi-square distribution with 8 degrees of freedom
+/// assert!((p_gamma_dist_inv(0.95, 4., 2.) - 15.50731305586545).abs() <= 1e-13);
+/// 
+/// let x = p_gamma_dist_inv(0.3, 2.5, 0.4);
+/// assert!((p_gamma_dist(x, 2.5, 0.4) - 0.3).abs() <= 1e-15);
+/// ```
+pub fn p_gamma_dist_inv(p: f64, shape: f64, scale: f64) -> f64 {
+    if scale.is_nan() || scale <= 0. || scale.is_infinite() { return f64::NAN; }
+    scale * p_gamma_inv(shape, p)
+}
+
+/// Return a value of the quantile function of the upper CDF of the gamma distribution
+/// with the shape *k* and the scale *θ* (the rate *1/θ*), that is, *θQ⁻¹(k, q)*.
+/// 
+/// ```
+/// use comonjo_mathfn::{q_gamma_dist, q_gamma_dist_inv};
+/// 
+/// let x = q_gamma_dist_inv(1e-50, 2.5, 0.4);
+/// assert!((q_gamma_dist(x, 2.5, 0.4) / 1e-50 - 1.).abs() <= 1e-13);
+/// ```
+pub fn q_gamma_dist_inv(q: f64, shape: f64, scale: f64) -> f64 {
+    if scale.is_nan() || scale <= 0. || scale.is_infinite() { return f64::NAN; }
+    scale * q_gamma_inv(shape, q)
+}
+
+/// Return a value of the quantile function (the inverse of the lower CDF) of the gamma distribution
+/// with the shape *k* and the rate *β*, that is, *P⁻¹(k, p)/β*.
+/// 
+/// ```
+/// use comonjo_mathfn::{p_gamma_dist_rate, p_gamma_dist_rate_inv};
+/// 
+/// let x = p_gamma_dist_rate_inv(0.3, 2.5, 2.5);
+/// assert!((p_gamma_dist_rate(x, 2.5, 2.5) - 0.3).abs() <= 1e-15);
+/// ```
+pub fn p_gamma_dist_rate_inv(p: f64, shape: f64, rate: f64) -> f64 {
+    if rate.is_nan() || rate <= 0. || rate.is_infinite() { return f64::NAN; }
+    p_gamma_inv(shape, p) / rate
+}
+
+/// Return a value of the quantile function of the upper CDF of the gamma distribution
+/// with the shape *k* and the rate *β*, that is, *Q⁻¹(k, q)/β*.
+/// 
+/// ```
+/// use comonjo_mathfn::{q_gamma_dist_rate, q_gamma_dist_rate_inv};
+/// 
+/// let x = q_gamma_dist_rate_inv(1e-50, 2.5, 2.5);
+/// assert!((q_gamma_dist_rate(x, 2.5, 2.5) / 1e-50 - 1.).abs() <= 1e-13);
+/// ```
+pub fn q_gamma_dist_rate_inv(q: f64, shape: f64, rate: f64) -> f64 {
+    if rate.is_nan() || rate <= 0. || rate.is_infinite() { return f64::NAN; }
+    q_gamma_inv(shape, q) / rate
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::igamma_fn::{p_gamma, q_gamma, erf, erfc, p_chi2, q_chi2, p_gamma_dist, q_gamma_dist, p_gamma_dist_rate, q_gamma_dist_rate};
+
+#[test]
+fn test_the_values_of_p_gamma_inv_out_of_the_domain(){
+    for (s, p) in non_finite_args2_with([-1., 0., 2.], [0.5, 1.5, -0.5]){
+        assert!(p_gamma_inv(s, p).is_nan(), "P⁻¹({}, {}) = NaN", s, p);
+        assert!(q_gamma_inv(s, p).is_nan(), "Q⁻¹({}, {}) = NaN", s, p);
+    }
+}
+
+#[test]
+fn test_the_inverse_regularized_gamma_function_properties(){
+    // s = 10^t, p = 10^u
+    should_the_same_mathfn2(
+        "log P(s, P⁻¹(s, p)) = log p",
+            |t, u| p_gamma(10_f64.powf(t), p_gamma_inv(10_f64.powf(t), 10_f64.powf(u))).ln(),
+            |_, u| 10_f64.powf(u).ln())
+        .filter(|t, u| p_gamma(10_f64.powf(t), f64::MIN_POSITIVE) <= 10_f64.powf(u))
+        .var0(|v| v.name("log s").range(-3., 4.).end())
+        .var1(|v| v.name("log p").range(-300., -0.3).end()).assert();
+
+    // the roots below f64::MIN_POSITIVE are the lower-tail asymptote (pΓ(s+1))^{1/s}
+    // rounded to the subnormal numbers or 0 (scaled by f64::MIN_POSITIVE to be compared)
+    should_the_same_mathfn2(
+        "P⁻¹(s, p) = (pΓ(s+1))^{1/s} if P(s, MIN_POSITIVE) > p",
+            |t, u| p_gamma_inv(10_f64.powf(t), 10_f64.powf(u)) / f64::MIN_POSITIVE,
+            |t, u| {
+                let s = 10_f64.powf(t);
+                ((u * std::f64::consts::LN_10 + log_gamma(s + 1.)) / s).exp() / f64::MIN_POSITIVE
+            })
+        .filter(|t, u| p_gamma(10_f64.powf(t), f64::MIN_POSITIVE) > 10_f64.powf(u))
+        .var0(|v| v.name("log s").range(-3., 4.).end())
+        .var1(|v| v.name("log p").range(-300., -0.3).end()).assert();
+
+    should_the_same_mathfn2(
+        "log Q(s, Q⁻¹(s, q)) = log q",
+            |t, u| q_gamma(10_f64.powf(t), q_gamma_inv(10_f64.powf(t), 10_f64.powf(u))).ln(),
+            |_, u| 10_f64.powf(u).ln())
+        .var0(|v| v.name("log s").range(-3., 4.).end())
+        .var1(|v| v.name("log q").range(-300., -0.3).end()).assert();
+
+    should_the_same_mathfn2(
+        "P(s, P⁻¹(s, p)) = p",
+            |s, p| p_gamma(s, p_gamma_inv(s, p)),
+            |_, p| p)
+        .var0(|v| v.name("s").range(0.1, 100.).end())
+        .var1(|v| v.name("p").range(0., 1.).end()).assert();
+
+    should_the_same_mathfn2(
+        "P⁻¹(s, P(s, x)) = x",
+            |s, x| p_gamma_inv(s, p_gamma(s, x)),
+            |_, x| x)
+        .filter(|s, x| p_gamma(s, x) < 0.999)
+        .var0(|v| v.name("s").range(0.5, 100.).end())
+        .var1(|v| v.name("x").range(0.1, 100.).end())
+        .epsilon(1e-10).assert();
+
+    should_the_same_mathfn2(
+        "Q⁻¹(s, q) = P⁻¹(s, 1-q)",
+            q_gamma_inv,
+            |s, q| p_gamma_inv(s, 1. - q))
+        .var0(|v| v.name("s").range(0.1, 100.).end())
+        .var1(|v| v.name("q").range(0.01, 0.99).end())
+        .epsilon(1e-10).assert();
+}
+
+#[test]
+fn test_the_values_of_p_gamma_inv_for_exponential_distribution(){
+    // P(1, x) = 1 - e^{-x}
+    should_the_same_mathfn(
+        "P⁻¹(1, p) = -log(1-p)",
+            |p| p_gamma_inv(1., p),
+            |p| -(-p).ln_1p())
+        .var0(|v| v.name("p").range(0., 0.999).end()).assert();
+
+    should_the_same_mathfn(
+        "Q⁻¹(1, q) = -log q",
+            |u| q_gamma_inv(1., 10_f64.powf(u)),
+            |u| -10_f64.powf(u).ln())
+        .var0(|v| v.name("log q").range(-300., -0.3).end()).assert();
+}
+
+#[test]
+fn test_the_values_of_p_gamma_inv_s_out_of_the_domain(){
+    for (x, p) in non_finite_args2_with([-1., 0., 2.], [0.5, 1.5, -0.5]){
+        assert!(p_gamma_inv_s(x, p).is_nan(), "P⁻¹ₛ({}, {}) = NaN", x, p);
+        assert!(q_gamma_inv_s(x, p).is_nan(), "Q⁻¹ₛ({}, {}) = NaN", x, p);
+    }
+}
+
+#[test]
+fn test_the_inverse_regularized_gamma_function_in_the_shape_parameter_properties(){
+    should_the_same_mathfn2(
+        "P(P⁻¹ₛ(x, p), x) = p",
+            |x, p| p_gamma(p_gamma_inv_s(x, p), x),
+            |_, p| p)
+        .var0(|v| v.name("x").range(0.1, 100.).end())
+        .var1(|v| v.name("p").range(0.01, 0.99).end()).assert();
+
+    should_the_same_mathfn2(
+        "Q(Q⁻¹ₛ(x, q), x) = q",
+            |x, q| q_gamma(q_gamma_inv_s(x, q), x),
+            |_, q| q)
+        .var0(|v| v.name("x").range(0.1, 100.).end())
+        .var1(|v| v.name("q").range(0.01, 0.99).end()).assert();
+
+    // x = 10^t, p = 10^u
+    should_the_same_mathfn2(
+        "log P(P⁻¹ₛ(x, p), x) = log p",
+            |t, u| p_gamma(p_gamma_inv_s(10_f64.powf(t), 10_f64.powf(u)), 10_f64.powf(t)).ln(),
+            |_, u| 10_f64.powf(u).ln())
+        .var0(|v| v.name("log x").range(-2., 3.).end())
+        .var1(|v| v.name("log p").range(-300., -0.3).end()).assert();
+
+    should_the_same_mathfn2(
+        "P⁻¹ₛ(x, P(s, x)) = s",
+            |s, x| p_gamma_inv_s(x, p_gamma(s, x)),
+            |s, _| s)
+        .filter(|s, x| 1e-3 < p_gamma(s, x) && p_gamma(s, x) < 0.999)
+        .var0(|v| v.name("s").range(0.5, 100.).end())
+        .var1(|v| v.name("x").range(0.1, 100.).end())
+        .epsilon(1e-10).assert();
+}
+
+#[test]
+fn test_the_values_of_q_gamma_inv_s_for_poisson_distribution(){
+    // Q(k+1, λ) = Σ_{i=0}^k e^{-λ}λ^i/i! and Q(1, λ) = e^{-λ}
+    should_the_same_mathfn(
+        "Q⁻¹ₛ(λ, e^{-λ}) = 1",
+            |lambda| q_gamma_inv_s(lambda, (-lambda).exp()),
+            |_| 1.)
+        .var0(|v| v.name("λ").range(0.1, 10.).end()).assert();
+}
+
+#[test]
+fn test_the_values_of_erf_inv_out_of_the_domain(){
+    for y in non_finite_values_with([-1.5, 2.5]){
+        assert!(erf_inv(y).is_nan(), "erf⁻¹({}) = NaN", y);
+        assert!(erfc_inv(y).is_nan(), "erfc⁻¹({}) = NaN", y);
+    }
+}
+
+#[test]
+fn test_the_inverse_error_function_properties(){
+    should_the_same_mathfn(
+        "erf(erf⁻¹(y)) = y",
+            |y| erf(erf_inv(y)),
+            |y| y)
+        .filter(|y| y.abs() < 1.)
+        .var0(|v| v.name("y").range(-1., 1.).end()).assert();
+
+    should_the_same_mathfn(
+        "erfc(erfc⁻¹(y)) = y",
+            |y| erfc(erfc_inv(y)),
+            |y| y)
+        .filter(|y| 0. < y && y < 2.)
+        .var0(|v| v.name("y").range(0., 2.).end()).assert();
+
+    should_the_same_mathfn(
+        "erf⁻¹(erf(x)) = x",
+            |x| erf_inv(erf(x)),
+            |x| x)
+        .var0(|v| v.name("x").range(-3., 3.).end())
+        .epsilon(1e-10).assert();
+
+    should_the_same_mathfn(
+        "erfc⁻¹(y) = erf⁻¹(1 - y)",
+            erfc_inv,
+            |y| erf_inv(1. - y))
+        .var0(|v| v.name("y").range(0.01, 1.99).end())
+        .epsilon(1e-10).assert();
+
+    // y = ±10^t
+    should_the_same_mathfn(
+        "log erfc(erfc⁻¹(y)) = log y",
+            |t| erfc(erfc_inv(10_f64.powf(t))).ln(),
+            |t| 10_f64.powf(t).ln())
+        .var0(|v| v.name("log y").range(-300., 0.).end()).assert();
+
+    should_the_same_mathfn(
+        "erf(erf⁻¹(y))/y = 1",
+            |t| erf(erf_inv(10_f64.powf(t))) / 10_f64.powf(t),
+            |_| 1.)
+        .var0(|v| v.name("log y").range(-8., 0.).end()).assert();
+
+    should_the_same_mathfn(
+        "erf⁻¹(y)/y = √π/2 for small y",
+            |t| erf_inv(10_f64.powf(t)) / 10_f64.powf(t),
+            |_| 0.5 * std::f64::consts::PI.sqrt())
+        .var0(|v| v.name("log y").range(-300., -10.).end()).assert();
+}
+
+#[test]
+fn test_the_values_of_p_normal_inv_out_of_the_domain(){
+    for p in non_finite_values_with([-0.5, 1.5]){
+        assert!(p_normal_inv(p).is_nan(), "P⁻¹({}) = NaN", p);
+        assert!(q_normal_inv(p).is_nan(), "Q⁻¹({}) = NaN", p);
+    }
+}
+
+#[test]
+fn test_the_ulp_errors_of_p_normal_inv(){
+    // the values by mpmath
+    for (p, expected) in [
+        (1e-300, -37.0470962993612),
+        (1e-250, -33.79958617269484),
+        (1e-200, -30.20559417957964),
+        (1e-150, -26.122961190593983),
+        (1e-100, -21.273453560965326),
+        (1e-50, -14.933337534788489),
+        (1e-20, -9.262340089798407),
+        (1e-10, -6.361340902404057),
+        (1e-5, -4.264890793922825),
+        (0.001, -3.0902323061678136),
+        (0.025, -1.9599639845400543),
+        (0.1, -1.2815515655446004),
+        (0.25, -0.6744897501960817),
+        (0.4, -0.2533471031357997),
+        (0.6, 0.2533471031357997),
+        (0.9, 1.2815515655446006),
+        (0.975, 1.9599639845400538),
+        (0.999, 3.090232306167813),
+        (0.9999999999, 6.361340889697422)]{
+        let x = p_normal_inv(p);
+        // the worst case is p = 1/4, where the rounding errors of P(x) - p (about 1 ULP of p)
+        // move the Halley step by about 1 ULP of x
+        let max_ulp = if p == 0.25 { 2 }else{ 1 };
+        assert!(ulp_distance(x, expected) <= max_ulp, "P⁻¹({}) = {} ({} ULP from {})", p, x, ulp_distance(x, expected), expected);
+    }
+}
+
+#[test]
+fn test_the_normal_quantile_function_properties(){
+    should_the_same_mathfn(
+        "P(P⁻¹(p)) = p",
+            |p| p_normal(p_normal_inv(p)),
+            |p| p)
+        .filter(|p| 0. < p && p < 1.)
+        .var0(|v| v.name("p").range(0., 1.).end()).assert();
+
+    // p = 10^t
+    should_the_same_mathfn(
+        "log P(P⁻¹(p)) = log p",
+            |t| p_normal(p_normal_inv(10_f64.powf(t))).ln(),
+            |t| 10_f64.powf(t).ln())
+        .var0(|v| v.name("log p").range(-300., -0.3).end()).assert();
+
+    should_the_same_mathfn(
+        "Q(Q⁻¹(q)) = q",
+            |q| q_normal(q_normal_inv(q)),
+            |q| q)
+        .filter(|q| 0. < q && q < 1.)
+        .var0(|v| v.name("q").range(0., 1.).end()).assert();
+
+    should_the_same_mathfn(
+        "P⁻¹(p) = √2 erf⁻¹(2p - 1)",
+            p_normal_inv,
+            |p| std::f64::consts::SQRT_2 * erf_inv(2. * p - 1.))
+        .var0(|v| v.name("p").range(0.01, 0.99).end())
+        .epsilon(1e-10).assert();
+}
+
+#[test]
+fn test_the_chi_square_quantile_function_properties(){
+    // n_f = 10^t
+    should_the_same_mathfn2(
+        "p_chi2(p_chi2_inv(p, n_f), n_f) = p",
+            |p, t| p_chi2(p_chi2_inv(p, 10_f64.powf(t)), 10_f64.powf(t)),
+            |p, _| p)
+        .filter(|p, t| 0. < p && p < 1. && p_chi2(f64::MIN_POSITIVE, 10_f64.powf(t)) <= p)
+        .var0(|v| v.name("p").range(0., 1.).end())
+        .var1(|v| v.name("log n_f").range(-2., 6.).end()).assert();
+
+    // the roots below f64::MIN_POSITIVE are the lower-tail asymptote 2(pΓ(n_f/2+1))^{2/n_f}
+    // rounded to the subnormal numbers or 0 (scaled by f64::MIN_POSITIVE to be compared)
+    should_the_same_mathfn2(
+        "p_chi2_inv(p, n_f) = 2(pΓ(n_f/2+1))^{2/n_f} if p_chi2(MIN_POSITIVE, n_f) > p",
+            |p, t| p_chi2_inv(p, 10_f64.powf(t)) / f64::MIN_POSITIVE,
+            |p, t| {
+                let s = 0.5 * 10_f64.powf(t);
+                2. * ((p.ln() + log_gamma(s + 1.)) / s).exp() / f64::MIN_POSITIVE
+            })
+        .filter(|p, t| 0. < p && p < 1. && p_chi2(f64::MIN_POSITIVE, 10_f64.powf(t)) > p)
+        .var0(|v| v.name("p").range(0., 1.).end())
+        .var1(|v| v.name("log n_f").range(-2., 6.).end()).assert();
+
+    should_the_same_mathfn2(
+        "q_chi2(q_chi2_inv(q, n_f), n_f) = q",
+            |q, t| q_chi2(q_chi2_inv(q, 10_f64.powf(t)), 10_f64.powf(t)),
+            |q, _| q)
+        .filter(|q, t| 0. < q && q < 1. && q_chi2(f64::MIN_POSITIVE, 10_f64.powf(t)) >= q)
+        .var0(|v| v.name("q").range(0., 1.).end())
+        .var1(|v| v.name("log n_f").range(-2., 6.).end()).assert();
+
+    should_the_same_mathfn2(
+        "q_chi2_inv(q, n_f) = 2((1-q)Γ(n_f/2+1))^{2/n_f} if q_chi2(MIN_POSITIVE, n_f) < q",
+            |q, t| q_chi2_inv(q, 10_f64.powf(t)) / f64::MIN_POSITIVE,
+            |q, t| {
+                let s = 0.5 * 10_f64.powf(t);
+                2. * (((-q).ln_1p() + log_gamma(s + 1.)) / s).exp() / f64::MIN_POSITIVE
+            })
+        .filter(|q, t| 0. < q && q < 1. && q_chi2(f64::MIN_POSITIVE, 10_f64.powf(t)) < q)
+        .var0(|v| v.name("q").range(0., 1.).end())
+        .var1(|v| v.name("log n_f").range(-2., 6.).end()).assert();
+
+    should_the_same_mathfn(
+        "p_chi2_inv(p, 1) = (P⁻¹(p))² for p > 1/2",
+            |p| p_chi2_inv(2. * p - 1., 1.),
+            |p| p_normal_inv(p).powi(2))
+        .var0(|v| v.name("p").range(0.6, 0.99).end())
+        .epsilon(1e-10).assert();
+
+    should_the_same_mathfn(
+        "p_chi2_inv(p, 2) = -2 log(1 - p)",
+            |p| p_chi2_inv(p, 2.),
+            |p| -2. * (-p).ln_1p())
+        .var0(|v| v.name("p").range(0., 0.999).end()).assert();
+
+    // the values by mpmath
+    let values = [
+        ((0.95, 1.), 3.8414588206941245),
+        ((0.95, 10.), 18.307038053275143),
+        ((0.05, 10.), 3.94029913611906),
+        ((0.99, 3.5), 12.329572300734483),
+        ((0.5, 0.01), 7.016667765235611e-61),
+        ((0.5, 1e6), 999999.3333334123)];
+    assert_reference_values("p_chi2_inv", |(p, n_f)| p_chi2_inv(p, n_f), &values, EPS);
+    assert_reference_values("q_chi2_inv", |(q, n_f)| q_chi2_inv(q, n_f), &values.map(|((p, n_f), x)| ((1. - p, n_f), x)), EPS);
+}
+
+#[test]
+fn test_the_values_of_p_gamma_dist_inv_out_of_the_domain(){
+    for (shape, scale) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if shape > 0. && shape.is_finite() && scale > 0. && scale.is_finite() { continue; }
+        assert!(p_gamma_dist_inv(0.5, shape, scale).is_nan(), "p_gamma_dist_inv(0.5, {}, {}) = NaN", shape, scale);
+        assert!(q_gamma_dist_inv(0.5, shape, scale).is_nan(), "q_gamma_dist_inv(0.5, {}, {}) = NaN", shape, scale);
+        assert!(p_gamma_dist_rate_inv(0.5, shape, scale).is_nan(), "p_gamma_dist_rate_inv(0.5, {}, {}) = NaN", shape, scale);
+        assert!(q_gamma_dist_rate_inv(0.5, shape, scale).is_nan(), "q_gamma_dist_rate_inv(0.5, {}, {}) = NaN", shape, scale);
+    }
+}
+
+#[test]
+fn test_the_gamma_distribution_quantile_function_properties(){
+    should_the_same_mathfn3(
+        "p_gamma_dist(p_gamma_dist_inv(p, k, θ), k, θ) = p",
+            |p, k, theta| p_gamma_dist(p_gamma_dist_inv(p, k, theta), k, theta),
+            |p, _, _| p)
+        .filter(|p, _, _| p < 0.999)
+        .var0(|v| v.name("p").range(0., 1.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 50.).n(20).end())
+        .var2(|v| v.name("θ").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_gamma_dist(q_gamma_dist_inv(q, k, θ), k, θ) = q",
+            |q, k, theta| q_gamma_dist(q_gamma_dist_inv(q, k, theta), k, theta),
+            |q, _, _| q)
+        .filter(|q, _, _| q > 0.001)
+        .var0(|v| v.name("q").range(0., 1.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 50.).n(20).end())
+        .var2(|v| v.name("θ").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn2(
+        "p_gamma_dist_inv(p, 1, θ) = -θ log(1 - p)",
+            |p, theta| p_gamma_dist_inv(p, 1., theta),
+            |p, theta| -theta * (-p).ln_1p())
+        .var0(|v| v.name("p").range(0., 1.).end())
+        .var1(|v| v.name("θ").range(0.1, 5.).end()).assert();
+
+    should_the_same_mathfn3(
+        "p_gamma_dist_rate(p_gamma_dist_rate_inv(p, k, β), k, β) = p",
+            |p, k, rate| p_gamma_dist_rate(p_gamma_dist_rate_inv(p, k, rate), k, rate),
+            |p, _, _| p)
+        .filter(|p, _, _| p < 0.999)
+        .var0(|v| v.name("p").range(0., 1.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 50.).n(20).end())
+        .var2(|v| v.name("β").range(0.1, 5.).n(20).end()).assert();
+
+    should_the_same_mathfn3(
+        "q_gamma_dist_rate(q_gamma_dist_rate_inv(q, k, β), k, β) = q",
+            |q, k, rate| q_gamma_dist_rate(q_gamma_dist_rate_inv(q, k, rate), k, rate),
+            |q, _, _| q)
+        .filter(|q, _, _| q > 0.001)
+        .var0(|v| v.name("q").range(0., 1.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 50.).n(20).end())
+        .var2(|v| v.name("β").range(0.1, 5.).n(20).end()).assert();
+}
@@ -1,3 +1,4 @@
+#[allow(clippy::wrong_self_convention)]
 pub trait IsInteger{
     fn is_integer(self) -> bool;
 }
@@ -1,13 +1,31 @@
 mod integer_util;
 mod gamma_fn;
 mod beta_fn;
+mod factorial_fn;
+mod pochhammer_fn;
+mod binomial_fn;
+mod multigamma_fn;
 mod igamma_fn;
+mod igamma_inv_fn;
+mod noncentral_chi2_fn;
 mod ibeta_fn;
+mod ibeta_inv_fn;
+mod discrete_fn;
+mod distribution;
 
-pub use crate::gamma_fn::{log_gamma, gamma};
-pub use crate::beta_fn::beta;
+pub use crate::gamma_fn::{log_gamma, log_gamma_sign, gamma, rgamma, digamma, trigamma, polygamma};
+pub use crate::beta_fn::{beta, log_beta, log_beta_sign};
+pub use crate::factorial_fn::{factorial, factorial_u128, double_factorial, double_factorial_u128, log_factorial};
+pub use crate::pochhammer_fn::{pochhammer, falling_factorial, gamma_ratio, gamma_delta_ratio};
+pub use crate::binomial_fn::{binomial, binomial_u128, log_binomial, multinomial};
+pub use crate::multigamma_fn::{log_multigamma, multigamma, log_multibeta};
 pub use crate::igamma_fn::*;
+pub use crate::igamma_inv_fn::*;
+pub use crate::noncentral_chi2_fn::*;
 pub use crate::ibeta_fn::*;
+pub use crate::ibeta_inv_fn::*;
+pub use crate::discrete_fn::*;
+pub use crate::distribution::*;
 
 #[cfg(test)]
 mod test_util;
\ No newline at end of file
@@ -0,0 +1,144 @@
+// The tests refer to
+// <a href="https://en.m.wikipedia.org/wiki/Multivariate_gamma_function">Multivariate gamma function</a>
+
+use crate::gamma_fn::log_gamma;
+use crate::beta_fn::log_beta;
+
+/** log π */
+const LOG_PI: f64 = 1.1447298858494002;
+
+/// Return the logarithm of the multivariate gamma function
+/// *log Γₚ(a) = p(p-1)/4 log π + Σⱼ log Γ(a + (1-j)/2)* (*j = 1, ..., p*).
+/// (The argument *a* must be greater than *(p-1)/2*.)
+/// 
+/// ```
+/// use comonjo_mathfn::{log_multigamma, log_gamma};
+/// 
+/// assert_eq!(log_multigamma(1, 3.5), log_gamma(3.5));
+/// assert!(log_multigamma(100, 1000.).is_finite());
+/// assert!(log_multigamma(3, 1.).is_nan());
+/// ```
+pub fn log_multigamma(p: u32, a: f64) -> f64 {
+    let pf = p as f64;
+    if a.is_nan() || a <= 0.5 * (pf - 1.) { return f64::NAN; }
+
+    (0..p).fold(0.25 * pf * (pf - 1.) * LOG_PI, |acc, j| acc + log_gamma(a - 0.5 * j as f64))
+}
+
+/// Return a value of the multivariate gamma function *Γₚ(a)*.
+/// (The argument *a* must be greater than *(p-1)/2*.)
+pub fn multigamma(p: u32, a: f64) -> f64 {
+    log_multigamma(p, a).exp()
+}
+
+/// Return the logarithm of the multivariate beta function (the normalizer of the Dirichlet distribution)
+/// *log Β(α₁, ..., αₙ) = Σᵢ log Γ(αᵢ) - log Γ(Σᵢ αᵢ)*.
+/// (The arguments must be positive and the slice must not be empty.)
+/// 
+/// ```
+/// use comonjo_mathfn::{log_multibeta, log_beta};
+/// 
+/// assert_eq!(log_multibeta(&[2., 3.]), log_beta(2., 3.));
+/// assert!((log_multibeta(&[1., 1., 1.]) - 0.5_f64.ln()).abs() <= 1e-14);  // Β(1, 1, 1) = 1/2!
+/// assert!(log_multibeta(&[]).is_nan());
+/// assert!(log_multibeta(&[1., -1.]).is_nan());
+/// ```
+pub fn log_multibeta(alphas: &[f64]) -> f64 {
+    if alphas.is_empty() || alphas.iter().any(|a| a.is_nan() || *a <= 0.) { return f64::NAN; }
+
+    let (a, rest) = alphas.split_first().unwrap();
+    if rest.is_empty() { return if a.is_infinite() { f64::NAN }else{ 0. }; }
+
+    // Β(α₁, ..., αₙ) = Πᵢ Β(α₁ + ... + αᵢ₋₁, αᵢ)
+    let mut sum = *a;
+    let mut result = 0.;
+    for b in rest {
+        result += log_beta(sum, *b);
+        sum += b;
+    }
+    result
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::gamma_fn::gamma;
+
+#[test]
+fn test_consts(){
+    assert_eq!(LOG_PI, std::f64::consts::PI.ln());
+}
+
+#[test]
+fn test_the_values_of_multigamma_out_of_the_domain(){
+    for p in 1..=5 {
+        let a_min = 0.5 * (p - 1) as f64;
+        assert!(log_multigamma(p, a_min).is_nan(), "log Γₚ((p-1)/2) = NaN at p = {}", p);
+        assert!(log_multigamma(p, a_min - 1.).is_nan(), "log Γₚ((p-3)/2) = NaN at p = {}", p);
+    }
+    assert!(log_multigamma(2, f64::NAN).is_nan(), "log Γ₂(NaN) = NaN");
+    assert_eq!(log_multigamma(2, f64::INFINITY), f64::INFINITY, "log Γ₂(∞) = ∞");
+    assert_eq!(log_multigamma(0, 1.), 0., "log Γ₀(a) = 0");
+}
+
+#[test]
+fn test_the_multigamma_function_properties(){
+    should_the_same_mathfn(
+        "Γ₁(a) = Γ(a)",
+            |a| multigamma(1, a),
+            gamma)
+        .var0(|v| v.name("a").range(0.1, 20.).end()).assert();
+
+    should_the_same_mathfn(
+        "Γ₂(a) = √π Γ(a)Γ(a-1/2)",
+            |a| multigamma(2, a),
+            |a| std::f64::consts::PI.sqrt() * gamma(a) * gamma(a - 0.5))
+        .var0(|v| v.name("a").range(0.6, 20.).end()).assert();
+
+    should_the_same_mathfn2(
+        "log Γₚ(a) = (p-1)/2 log π + log Γ(a) + log Γₚ₋₁(a-1/2)",
+            |p, a| log_multigamma(p as u32, a),
+            |p, a| 0.5 * (p - 1.) * LOG_PI + log_gamma(a) + log_multigamma(p as u32 - 1, a - 0.5))
+        .filter(|p, a| a > 0.5 * (p - 1.))
+        .var0(|v| v.name("p").range(1., 10.).is_integer(true).end())
+        .var1(|v| v.name("a").range(0., 100.).end()).assert();
+
+    should_the_same_mathfn2(
+        "log Γₚ(a) = (p-1)/2 log π + log Γₚ₋₁(a) + log Γ(a+(1-p)/2)",
+            |p, a| log_multigamma(p as u32, a),
+            |p, a| 0.5 * (p - 1.) * LOG_PI + log_multigamma(p as u32 - 1, a) + log_gamma(a + 0.5 * (1. - p)))
+        .filter(|p, a| a > 0.5 * (p - 1.))
+        .var0(|v| v.name("p").range(1., 10.).is_integer(true).end())
+        .var1(|v| v.name("a").range(0., 100.).end()).assert();
+}
+
+#[test]
+fn test_the_multibeta_function_properties(){
+    should_the_same_mathfn2(
+        "log Β(a, b, 1) = log Β(a, b) - log(a+b)",
+            |a, b| log_multibeta(&[a, b, 1.]),
+            |a, b| log_beta(a, b) - (a + b).ln())
+        .var0(|v| v.name("a").range(0.1, 50.).end())
+        .var1(|v| v.name("b").range(0.1, 50.).end()).assert();
+
+    should_the_same_mathfn2(
+        "Β(a, b, c) = Β(a, b)Β(a+b, c)",
+            |a, b| log_multibeta(&[a, b, 2.5]),
+            |a, b| log_beta(a, b) + log_beta(a + b, 2.5))
+        .var0(|v| v.name("a").range(0.1, 50.).end())
+        .var1(|v| v.name("b").range(0.1, 50.).end()).assert();
+
+    should_the_same_mathfn2(
+        "Β(a, b, c) = Γ(a)Γ(b)Γ(c)/Γ(a+b+c)",
+            |a, b| log_multibeta(&[a, b, 2.5]),
+            |a, b| (gamma(a) * gamma(b) * gamma(2.5) / gamma(a + b + 2.5)).ln())
+        .var0(|v| v.name("a").range(0.1, 50.).end())
+        .var1(|v| v.name("b").range(0.1, 50.).end()).assert();
+
+    // the values by mpmath, where log Γ(αᵢ) cancel each other
+    let values: [(&[f64], f64); 2] = [(&[1e10, 1e10, 0.5], -13862943632.733454), (&[1e6, 3., 2.5, 1e6], -1386378.8231566194)];
+    assert_reference_values("log Β(α)", log_multibeta, &values, EPS);
+
+    let alphas = [0.5; 1000];
+    assert!(log_multibeta(&alphas).is_finite(), "log Β(1/2, ..., 1/2) (1000 arguments) is finite");
+}
@@ -0,0 +1,217 @@
+// The tests refer to
+// <a href="https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution">Noncentral chi-squared distribution</a>
+
+use crate::gamma_fn::log_gamma;
+use crate::igamma_fn::{p_gamma, q_gamma, power_exp_by_gamma};
+use crate::igamma_inv_fn::find_root_in_bracket;
+
+const MAX_TERMS: usize = 100_000;
+
+/// Return *Σ_j w_j P(a+j, y)* (or *Σ_j w_j Q(a+j, y)* if `upper` is true) with the Poisson weights *w_j = e^{-μ}μ^j/j!*,
+/// summed from the mode *j = ⌊μ⌋* outward.
+///
+/// Ref: C. G. Ding, Algorithm AS 275 (1992)
+fn poisson_weighted_gamma_sum(a: f64, y: f64, mu: f64, upper: bool) -> f64 {
+    // (F(a+j, y), t_j) by the direct evaluation
+    let direct = |j: f64| {
+        let f = if upper { q_gamma(a + j, y) }else{ p_gamma(a + j, y) };
+        (f, power_exp_by_gamma(a + j, y, log_gamma(a + j)) / (a + j))
+    };
+
+    let j0 = mu.floor();
+    let w0 = if mu == 0. { 1. }else{ power_exp_by_gamma(j0 + 1., mu, log_gamma(j0 + 1.)) / mu };
+    let (f0, t0) = direct(j0);
+    let mut sum = w0 * f0;
+
+    // downward: F(a+j-1, y) = F(a+j, y) ± t_{j-1}, t_{j-1} = t_j (a+j)/y
+    let (mut j, mut w, mut f, mut t) = (j0, w0, f0, t0);
+    for _ in 0..MAX_TERMS {
+        if j == 0. { break; }
+        w *= j / mu;
+        j -= 1.;
+        if t == 0. {
+            (f, t) = direct(j);
+        }else{
+            t *= (a + j + 1.) / y;
+            f = if upper { (f - t).max(0.) }else{ (f + t).min(1.) };
+        }
+        let term = w * f;
+        sum += term;
+        // P increases and Q decreases as j decreases
+        if (if upper { term }else{ w }) <= f64::EPSILON * sum { break; }
+    }
+
+    // upward: F(a+j+1, y) = F(a+j, y) ∓ t_j, t_{j+1} = t_j y/(a+j+1)
+    let (mut j, mut w, mut f, mut t) = (j0, w0, f0, t0);
+    for _ in 0..MAX_TERMS {
+        f = if upper { (f + t).min(1.) }else{ (f - t).max(0.) };
+        t *= y / (a + j + 1.);
+        j += 1.;
+        w *= mu / j;
+        if t == 0. { (f, t) = direct(j); }
+        let term = w * f;
+        sum += term;
+        // P decreases and Q increases as j increases
+        if (if upper { w }else{ term }) <= f64::EPSILON * sum { break; }
+    }
+
+    sum.min(1.)
+}
+
+fn is_out_of_domain(x: f64, k: f64, lambda: f64) -> bool {
+    x.is_nan() || k.is_nan() || k <= 0. || k.is_infinite() || lambda.is_nan() || lambda < 0. || lambda.is_infinite()
+}
+
+/// Return a value of the lower CDF of the noncentral chi-square distribution
+/// with *k* degrees of freedom and the noncentrality parameter *λ*.
+///
+/// ```
+/// use comonjo_mathfn::{p_noncentral_chi2, p_chi2};
+///
+/// assert_eq!(p_noncentral_chi2(3., 2., 0.), p_chi2(3., 2.));
+/// assert!((p_noncentral_chi2(3., 2., 1.5) - 0.5513141413937851).abs() <= 1e-15);
+/// ```
+pub fn p_noncentral_chi2(x: f64, k: f64, lambda: f64) -> f64 {
+    if is_out_of_domain(x, k, lambda) { return f64::NAN; }
+    if x <= 0. { return 0.; }
+    if x.is_infinite() { return 1.; }
+
+    poisson_weighted_gamma_sum(0.5 * k, 0.5 * x, 0.5 * lambda, false)
+}
+
+/// Return a value of the upper CDF of the noncentral chi-square distribution
+/// with *k* degrees of freedom and the noncentrality parameter *λ*.
+///
+/// ```
+/// use comonjo_mathfn::q_noncentral_chi2;
+///
+/// assert!((q_noncentral_chi2(3., 2., 1.5) - 0.44868585860621485).abs() <= 1e-15);
+/// ```
+pub fn q_noncentral_chi2(x: f64, k: f64, lambda: f64) -> f64 {
+    if is_out_of_domain(x, k, lambda) { return f64::NAN; }
+    if x <= 0. { return 1.; }
+    if x.is_infinite() { return 0.; }
+
+    poisson_weighted_gamma_sum(0.5 * k, 0.5 * x, 0.5 * lambda, true)
+}
+
+/// Return a value of the quantile function (the inverse of the lower CDF)
+/// of the noncentral chi-square distribution. (The argument *p* must be in *[0, 1]*.)
+///
+/// The root is bracketed by doubling or halving the mean *k + λ*.
+///
+/// ```
+/// use comonjo_mathfn::{p_noncentral_chi2, p_noncentral_chi2_inv};
+///
+/// let x = p_noncentral_chi2_inv(0.95, 3., 10.);
+/// assert!((p_noncentral_chi2(x, 3., 10.) - 0.95).abs() <= 1e-15);
+/// ```
+pub fn p_noncentral_chi2_inv(p: f64, k: f64, lambda: f64) -> f64 {
+    if is_out_of_domain(p, k, lambda) || !(0. ..=1.).contains(&p) { return f64::NAN; }
+    if p == 0. { return 0.; }
+    if p == 1. { return f64::INFINITY; }
+
+    // g(x) is increasing in x
+    let q = 1. - p;
+    let g = |x: f64| if p <= 0.5 {
+        p_noncentral_chi2(x, k, lambda) - p
+    }else{
+        q - q_noncentral_chi2(x, k, lambda)
+    };
+
+    let mut lo = k + lambda;
+    let mut hi = lo;
+    if g(lo) < 0. {
+        while g(hi) < 0. {
+            lo = hi; hi *= 2.;
+            if hi > f64::MAX / 2. { return f64::INFINITY; }
+        }
+    }else{
+        while g(lo) > 0. {
+            hi = lo; lo *= 0.5;
+            if lo < f64::MIN_POSITIVE { return 0.; }
+        }
+    }
+
+    find_root_in_bracket(g, lo, hi)
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::igamma_fn::{p_chi2, q_chi2, p_normal};
+
+#[test]
+fn test_the_values_of_noncentral_chi2_out_of_the_domain(){
+    for (k, lambda) in non_finite_args2_with([-1., 0., 2.], [-1., 0., 2.]){
+        if k > 0. && k.is_finite() && lambda >= 0. && lambda.is_finite() { continue; }
+        assert!(p_noncentral_chi2(1., k, lambda).is_nan(), "P(1; {}, {}) = NaN", k, lambda);
+        assert!(q_noncentral_chi2(1., k, lambda).is_nan(), "Q(1; {}, {}) = NaN", k, lambda);
+        assert!(p_noncentral_chi2_inv(0.5, k, lambda).is_nan(), "P⁻¹(0.5; {}, {}) = NaN", k, lambda);
+    }
+}
+
+#[test]
+fn test_the_noncentral_chi_square_distribution_cdf_properties(){
+    should_the_same_mathfn2(
+        "P(x; k, 0) = p_chi2(x, k)",
+            |x, k| p_noncentral_chi2(x, k, 0.),
+            p_chi2)
+        .var0(|v| v.name("x").range(0., 50.).end())
+        .var1(|v| v.name("k").range(0.1, 20.).end()).assert();
+
+    should_the_same_mathfn2(
+        "Q(x; k, 0) = q_chi2(x, k)",
+            |x, k| q_noncentral_chi2(x, k, 0.),
+            q_chi2)
+        .var0(|v| v.name("x").range(0., 50.).end())
+        .var1(|v| v.name("k").range(0.1, 20.).end()).assert();
+
+    should_the_same_mathfn3(
+        "Q(x; k, λ) = 1 - P(x; k, λ)",
+            q_noncentral_chi2,
+            |x, k, lambda| 1. - p_noncentral_chi2(x, k, lambda))
+        .var0(|v| v.name("x").range(0., 100.).n(20).end())
+        .var1(|v| v.name("k").range(0.1, 20.).n(20).end())
+        .var2(|v| v.name("λ").range(0., 50.).n(20).end()).assert();
+
+    // the noncentral chi-square variable with k = 1 is (Z + √λ)²
+    should_the_same_mathfn2(
+        "P(x; 1, λ) = Φ(√x - √λ) - Φ(-√x - √λ)",
+            |x, lambda| p_noncentral_chi2(x, 1., lambda),
+            |x, lambda| p_normal(x.sqrt() - lambda.sqrt()) - p_normal(-x.sqrt() - lambda.sqrt()))
+        .var0(|v| v.name("x").range(0., 100.).end())
+        .var1(|v| v.name("λ").range(0., 100.).end()).assert();
+
+    // large λ
+    should_the_same_mathfn2(
+        "P(λ + 2u√λ; 1, λ) = Φ(√x - √λ) - Φ(-√x - √λ) for large λ",
+            |u, lambda| p_noncentral_chi2(lambda + 2. * u * lambda.sqrt(), 1., lambda),
+            |u, lambda| {
+                let x = lambda + 2. * u * lambda.sqrt();
+                p_normal(x.sqrt() - lambda.sqrt()) - p_normal(-x.sqrt() - lambda.sqrt())
+            })
+        .var0(|v| v.name("u").range(-3., 3.).n(20).end())
+        .var1(|v| v.name("λ").range(1e3, 1e5).n(20).end())
+        .epsilon(1e-10).assert();
+}
+
+#[test]
+fn test_the_noncentral_chi_square_quantile_function_properties(){
+    should_the_same_mathfn3(
+        "P(P⁻¹(p; k, λ); k, λ) = p",
+            |p, k, lambda| p_noncentral_chi2(p_noncentral_chi2_inv(p, k, lambda), k, lambda),
+            |p, _, _| p)
+        .filter(|p, _, _| 0. < p && p < 1.)
+        .var0(|v| v.name("p").range(0., 1.).n(20).end())
+        .var1(|v| v.name("k").range(0.5, 20.).n(20).end())
+        .var2(|v| v.name("λ").range(0., 50.).n(20).end()).assert();
+
+    should_the_same_mathfn2(
+        "P⁻¹(p; k, 0) = p_chi2_inv(p, k)",
+            |p, k| p_noncentral_chi2_inv(p, k, 0.),
+            crate::igamma_inv_fn::p_chi2_inv)
+        .var0(|v| v.name("p").range(0.01, 0.99).end())
+        .var1(|v| v.name("k").range(0.5, 20.).end())
+        .epsilon(1e-10).assert();
+}
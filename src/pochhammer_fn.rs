@@ -0,0 +1,178 @@
+// The tests refer to
+// <a href="https://en.m.wikipedia.org/wiki/Falling_and_rising_factorials">Falling and rising factorials</a>
+
+use crate::gamma_fn::{N, stirling_series, log_gamma_sign};
+
+/// The maximum number of factors multiplied directly for an integer order.
+const MAX_PRODUCT_TERMS: f64 = 100.;
+
+/// The maximum number of shifts *Γ(x) = Γ(x+1)/x* applied before the Stirling series is used.
+const MAX_SHIFTS: f64 = 1000.;
+
+/// Return the ratio of the gamma functions *Γ(a+δ)/Γ(a)*, which is accurate for large *a* even if *δ* is small.
+/// 
+/// ```
+/// use comonjo_mathfn::gamma_delta_ratio;
+/// 
+/// // Γ(a+1/2)/Γ(a) ~ √a (1 - 1/(8a)) for large a
+/// let a: f64 = 1e10;
+/// let exp = a.sqrt() * (1. - 1. / (8. * a));
+/// assert!((gamma_delta_ratio(a, 0.5) - exp).abs() <= 1e-15 * exp);
+/// ```
+pub fn gamma_delta_ratio(mut a: f64, d: f64) -> f64 {
+    if a.is_nan() || d.is_nan() { return f64::NAN; }
+    if d == 0. { return 1.; }
+    if d.fract() == 0. && d.abs() <= MAX_PRODUCT_TERMS { return rising_product(a, d); }
+
+    let mut b = a + d;
+    let a_is_pole = a <= 0. && a.fract() == 0.;
+    let b_is_pole = b <= 0. && b.fract() == 0.;
+    if b_is_pole { return f64::NAN; }
+    if a_is_pole { return 0.; }  // 1/Γ(a) = 0
+
+    if a.is_infinite() || d.is_infinite() || N - a.min(b) > MAX_SHIFTS {
+        let (log_gamma_a, sign_a) = log_gamma_sign(a);
+        let (log_gamma_b, sign_b) = log_gamma_sign(b);
+        return sign_a * sign_b * (log_gamma_b - log_gamma_a).exp();
+    }
+
+    // Γ(a+δ)/Γ(a) = [Γ(a+m+δ)/Γ(a+m)] Π_{k<m} (a+k)/(a+δ+k)
+    let mut shift = 1.;
+    while a < N || b < N {
+        shift *= a / b;
+        a += 1.;
+        b += 1.;
+    }
+
+    // the power (a+δ)^δ is factored out of the exponential to keep the accuracy for large a
+    let rest = (a - 0.5) * (d / a).ln_1p() - d + stirling_series(b) - stirling_series(a);
+    let power = b.powf(d);
+    if power.is_finite() && power != 0. {
+        shift * power * rest.exp()
+    }else{
+        shift * (d * b.ln() + rest).exp()
+    }
+}
+
+/// Return *a(a+1)...(a+n-1)* for a positive integer *n*
+/// or *1/((a-1)(a-2)...(a+n))* for a negative integer *n*.
+/// (The latter is NaN if a factor is 0, that is, only *Γ(a+n)* is a pole.)
+fn rising_product(a: f64, n: f64) -> f64 {
+    let n = n as i32;
+    if n >= 0 {
+        (0..n).fold(1., |acc, k| acc * (a + k as f64))
+    }else{
+        let product = (n..0).fold(1., |acc, k| acc * (a + k as f64));
+        if product == 0. { f64::NAN }else{ 1. / product }
+    }
+}
+
+/// Return the ratio of the gamma functions *Γ(a)/Γ(b)*.
+/// 
+/// ```
+/// use comonjo_mathfn::gamma_ratio;
+/// 
+/// // Γ(200)/Γ(198) = 199 × 198 (each of the gamma functions overflows)
+/// assert!((gamma_ratio(200., 198.) - 199. * 198.).abs() <= 1e-10);
+/// ```
+pub fn gamma_ratio(a: f64, b: f64) -> f64 {
+    gamma_delta_ratio(b, a - b)
+}
+
+/// Return the Pochhammer symbol (the rising factorial) *(a)ₙ = Γ(a+n)/Γ(a)*.
+/// The order *n* is not necessarily an integer.
+/// 
+/// ```
+/// use comonjo_mathfn::pochhammer;
+/// 
+/// assert_eq!(pochhammer(3., 4.), 3. * 4. * 5. * 6.);
+/// assert_eq!(pochhammer(-3., 2.), (-3.) * (-2.));
+/// assert_eq!(pochhammer(-3., 5.), 0.);
+/// ```
+pub fn pochhammer(a: f64, n: f64) -> f64 {
+    gamma_delta_ratio(a, n)
+}
+
+/// Return the falling factorial *a(a-1)...(a-n+1) = Γ(a+1)/Γ(a-n+1)*.
+/// The order *n* is not necessarily an integer.
+/// 
+/// ```
+/// use comonjo_mathfn::falling_factorial;
+/// 
+/// assert_eq!(falling_factorial(6., 3.), 6. * 5. * 4.);
+/// assert_eq!(falling_factorial(3., 5.), 0.);
+/// ```
+pub fn falling_factorial(a: f64, n: f64) -> f64 {
+    if n.fract() == 0. && (0. ..=MAX_PRODUCT_TERMS).contains(&n) {
+        (0..n as i32).fold(1., |acc, k| acc * (a - k as f64))
+    }else{
+        gamma_ratio(a + 1., a + 1. - n)
+    }
+}
+
+#[cfg(test)]
+use crate::test_util::*;
+#[cfg(test)]
+use crate::gamma_fn::gamma;
+
+#[test]
+fn test_the_values_of_pochhammer_at_the_specific_points(){
+    use crate::factorial_fn::factorial;
+
+    for n in 0..=20 {
+        assert_eq!(pochhammer(1., n as f64), factorial(n), "(1)ₙ = n! at n = {}", n);
+        assert_eq!(falling_factorial(n as f64, n as f64), factorial(n), "n(n-1)...1 = n! at n = {}", n);
+    }
+
+    assert_eq!(gamma_delta_ratio(5.5, 0.), 1., "Γ(a)/Γ(a) = 1");
+    assert_eq!(gamma_delta_ratio(-2., 0.5), 0., "Γ(-3/2)/Γ(-2) = 0");
+    assert!(gamma_delta_ratio(0.5, -2.5).is_nan(), "Γ(-2)/Γ(1/2) = NaN");
+    assert!(gamma_delta_ratio(2., -3.).is_nan(), "Γ(-1)/Γ(2) = NaN");
+    assert_eq!(gamma_delta_ratio(-1., -2.), 1. / 6., "Γ(-3)/Γ(-1) = 1/((-2)(-3))");
+    assert_approximately(gamma_delta_ratio(-1e-20, 0.5) / 1e-20, -std::f64::consts::PI.sqrt(), EPS, "Γ(1/2 - 1e-20)/Γ(-1e-20)");
+    assert!(gamma_delta_ratio(f64::NAN, 1.).is_nan(), "Γ(NaN+1)/Γ(NaN) = NaN");
+}
+
+#[test]
+fn test_the_pochhammer_function_properties(){
+    const DELTA: f64 = 1e-3;
+
+    should_the_same_mathfn2(
+        "(a)ₙ = Γ(a+n)/Γ(a)",
+            pochhammer,
+            |a, n| gamma(a + n) / gamma(a))
+        .filter(|a, n| ![a, a + n].iter().any(|t| is_close_to_a_non_positive_integer(*t, DELTA)))
+        .var0(|v| v.name("a").range(-10., 20.).end())
+        .var1(|v| v.name("n").range(-5., 10.).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "(a)_{m+n} = (a)_m (a+m)_n",
+            |a, n| pochhammer(a, n + 2.5),
+            |a, n| pochhammer(a, 2.5) * pochhammer(a + 2.5, n))
+        .var0(|v| v.name("a").range(3., 40.).end())
+        .var1(|v| v.name("n").range(-5., 5.).end()).assert();
+
+    should_the_same_mathfn2(
+        "Γ(a+δ)/Γ(a) = a^δ(1 + δ(δ-1)/(2a)) + O(a^{-2})",
+            gamma_delta_ratio,
+            |a, d| a.powf(d) * (1. + d * (d - 1.) / (2. * a)))
+        .var0(|v| v.name("a").range(1e8, 1e12).end())
+        .var1(|v| v.name("δ").range(-1., 1.).end())
+        .epsilon(1e-14).assert();
+
+    should_the_same_mathfn2(
+        "Γ(a)/Γ(b) = 1/(Γ(b)/Γ(a))",
+            gamma_ratio,
+            |a, b| 1. / gamma_ratio(b, a))
+        .var0(|v| v.name("a").range(0.1, 300.).end())
+        .var1(|v| v.name("b").range(0.1, 300.).end())
+        .epsilon(1e-11).assert();
+
+    should_the_same_mathfn2(
+        "a(a-1)...(a-n+1) = (-1)ⁿ(-a)ₙ",
+            falling_factorial,
+            |a, n| (-1_f64).powi(n as i32) * pochhammer(-a, n))
+        .var0(|v| v.name("a").range(-10., 10.).end())
+        .var1(|v| v.name("n").range(0., 10.).is_integer(true).end()).assert();
+}
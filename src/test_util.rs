@@ -12,7 +12,7 @@ pub fn is_close_to_a_non_positive_integer(x: f64, delta: f64) -> bool {
 }
 
 pub fn repeat<F>(n: usize, f: F)
-    where F: Fn() -> ()
+    where F: Fn()
 {
     for _ in 0..n { f(); }
 }
@@ -70,6 +70,24 @@ pub fn assert_approximately(x: f64, y: f64, eps: f64, message: &str){
     } 
 }
 
+/// Assert that `f(args)` agrees with each reference value (e.g. by mpmath) in `values` within the relative error `eps`.
+pub fn assert_reference_values<A, F>(name: &str, f: F, values: &[(A, f64)], eps: f64)
+    where A: std::fmt::Debug + Copy, F: Fn(A) -> f64
+{
+    for &(args, expected) in values {
+        let y = f(args);
+        assert!(y == expected || (y / expected - 1.).abs() <= eps,
+            "{} at {:?}: {} != {} (relative error: {:e})", name, args, y, expected, (y / expected - 1.).abs());
+    }
+}
+
+/// Return the number of representable f64 values between x and y (the error in ULP).
+pub fn ulp_distance(x: f64, y: f64) -> u64 {
+    if x == y { return 0; }
+    if x.is_nan() || y.is_nan() || x.signum() != y.signum() { return u64::MAX; }
+    (x.to_bits() as i64 - y.to_bits() as i64).unsigned_abs()
+}
+
 pub fn assert_diverging(x: f64, big_value: f64, message: &str){
     assert!(x.is_nan() || x.is_infinite() || x.abs() >= big_value,
          "{:?}: {} is not diverging (big value: {})", message, x, big_value);
@@ -108,7 +126,7 @@ impl<'a> TestVariable<'a> {
         self
     }
 
-    pub fn end(&self){()}
+    pub fn end(&self){}
     
     fn at_even_intervals<F>(&self, f: F) where F: Fn(f64) {
         if self._is_integer {
@@ -416,6 +434,7 @@ pub fn non_finite_args2_with<V0, V1>(finite_values0: V0, finite_values1: V1)
     non_finite_values_with(finite_values0).zip(non_finite_values_with(finite_values1))
 }
 
+#[allow(clippy::wrong_self_convention)]
 pub trait AsF64Vec{
     fn as_f64_iter(self) -> Box<dyn Iterator<Item=f64>>;
 }